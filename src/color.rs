@@ -38,7 +38,7 @@ use std::fmt;
 /// 24-bit RGB color pixel.
 ///
 /// This is the fundamental representation for RGB colors.
-#[derive(Copy, Clone, Default, PartialEq)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
 pub struct PixelColor {
     pub red: u8,
@@ -124,6 +124,45 @@ impl PixelColor {
         Rgb565::from(self).split_be()
     }
 
+    /// Returns `true` if both colors are displayed the same, after quantizing them to RGB565.
+    pub fn approx_eq(&self, other: &PixelColor) -> bool {
+        Rgb565::from(self) == Rgb565::from(other)
+    }
+
+    /// Linearly interpolate between this colour, at `t = 0.0`, and `other`, at `t = 1.0`.
+    /// Values of `t` outside of the `0.0..=1.0` range are clamped.
+    pub fn lerp(&self, other: &PixelColor, t: f32) -> PixelColor {
+        let t = t.max(0.0).min(1.0);
+        let lerp_byte =
+            |a: u8, b: u8| (f32::from(a) + (f32::from(b) - f32::from(a)) * t).round() as u8;
+        PixelColor {
+            red: lerp_byte(self.red, other.red),
+            green: lerp_byte(self.green, other.green),
+            blue: lerp_byte(self.blue, other.blue),
+        }
+    }
+
+    /// Multiply each channel of this colour by the channel of `other`, as `a * b / 255`.
+    /// Multiplying by `PixelColor::WHITE` leaves the colour unchanged.
+    pub fn multiply(&self, other: &PixelColor) -> PixelColor {
+        fn multiply_byte(a: u8, b: u8) -> u8 {
+            ((u16::from(a) * u16::from(b) + 127) / 255) as u8
+        }
+        PixelColor {
+            red: multiply_byte(self.red, other.red),
+            green: multiply_byte(self.green, other.green),
+            blue: multiply_byte(self.blue, other.blue),
+        }
+    }
+
+    /// Screen each channel of this colour with the channel of `other`, as
+    /// `255 - (255 - a) * (255 - b) / 255`. Screening with `PixelColor::BLACK` leaves the
+    /// colour unchanged.
+    pub fn screen(&self, other: &PixelColor) -> PixelColor {
+        let inverted = |c: &PixelColor| PixelColor::new(255 - c.red, 255 - c.green, 255 - c.blue);
+        inverted(&inverted(self).multiply(&inverted(other)))
+    }
+
     /// Sets the brightness of this colour.
     ///
     /// The `scale` value should be between 0 and 1. Values outside this range
@@ -144,6 +183,104 @@ impl PixelColor {
             blue: scale_byte(self.blue, scale),
         }
     }
+
+    /// Sets the perceived brightness of this colour.
+    ///
+    /// The `scale` is taken as a gamma-encoded (sRGB) brightness, and converted to the
+    /// linear light that the LEDs emit before dimming, so that equal steps of `scale` look
+    /// equally spaced. A `scale` of `0.5` keeps about 21% of the light, where `dim(0.5)`
+    /// keeps half of it and looks much brighter than half.
+    ///
+    /// The `scale` value should be between 0 and 1. Values outside this range
+    /// are clamped.
+    pub fn dim_perceptual(self, scale: f32) -> PixelColor {
+        self.dim(srgb_to_linear(scale.max(0.0).min(1.0)))
+    }
+
+    /// Relative luminance of this colour, as defined by WCAG 2.0, in the `0.0..=1.0` range.
+    pub fn relative_luminance(&self) -> f32 {
+        let linearize = |b: u8| srgb_to_linear(f32::from(b) / 255.0);
+        0.2126 * linearize(self.red)
+            + 0.7152 * linearize(self.green)
+            + 0.0722 * linearize(self.blue)
+    }
+
+    /// Returns either `PixelColor::BLACK` or `PixelColor::WHITE`, whichever has the
+    /// highest contrast ratio when drawn over this colour.
+    pub fn contrasting(&self) -> PixelColor {
+        let luminance = self.relative_luminance();
+        // contrast against black: (L + 0.05) / 0.05; against white: 1.05 / (L + 0.05)
+        if (luminance + 0.05) * (luminance + 0.05) > 0.05 * 1.05 {
+            PixelColor::BLACK
+        } else {
+            PixelColor::WHITE
+        }
+    }
+
+    /// Create a new LED pixel color from its hue, in degrees, and its saturation and value,
+    /// in the `0.0..=1.0` range. The hue wraps around `360.0`, and the saturation and value
+    /// are clamped.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let hue = wrap_modulo(hue, 360.0) / 60.0;
+        let saturation = saturation.max(0.0).min(1.0);
+        let value = value.max(0.0).min(1.0);
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        let (red, green, blue) = match hue as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = value - chroma;
+        let to_byte = |c: f32| ((c + m) * 255.0).round() as u8;
+        PixelColor::new(to_byte(red), to_byte(green), to_byte(blue))
+    }
+
+    /// Returns the hue, in degrees in the `0.0..360.0` range, and the saturation and value,
+    /// in the `0.0..=1.0` range, of this colour.
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let (red, green, blue) = (
+            f32::from(self.red) / 255.0,
+            f32::from(self.green) / 255.0,
+            f32::from(self.blue) / 255.0,
+        );
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let chroma = max - min;
+        let hue = if chroma == 0.0 {
+            0.0
+        } else if max == red {
+            60.0 * wrap_modulo((green - blue) / chroma, 6.0)
+        } else if max == green {
+            60.0 * ((blue - red) / chroma + 2.0)
+        } else {
+            60.0 * ((red - green) / chroma + 4.0)
+        };
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+        (hue, saturation, max)
+    }
+}
+
+// Decode a gamma-encoded sRGB component, in the `0.0..=1.0` range, into linear light.
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.039_28 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Wrap a value into the `0.0..modulo` range.
+fn wrap_modulo(value: f32, modulo: f32) -> f32 {
+    let wrapped = value % modulo;
+    if wrapped < 0.0 {
+        wrapped + modulo
+    } else {
+        wrapped
+    }
 }
 
 impl From<Rgb565> for PixelColor {
@@ -165,6 +302,145 @@ impl Into<(u8, u8, u8)> for PixelColor {
     }
 }
 
+/// A `PixelColor` with an alpha channel, for compositing layers of color. An `alpha` of `0`
+/// is fully transparent, and an `alpha` of `255` is fully opaque.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
+pub struct Rgba {
+    pub color: PixelColor,
+    pub alpha: u8,
+}
+
+impl Rgba {
+    pub const TRANSPARENT: Rgba = Rgba {
+        color: PixelColor::BLACK,
+        alpha: 0,
+    };
+
+    /// Create a new color with the given `alpha`.
+    pub fn new(color: PixelColor, alpha: u8) -> Self {
+        Rgba { color, alpha }
+    }
+
+    /// Composite this color over the `bottom` color, with the source-over operator.
+    pub fn over(&self, bottom: Rgba) -> Rgba {
+        let (top_alpha, bottom_alpha) = (u32::from(self.alpha), u32::from(bottom.alpha));
+        // Alpha of the bottom color that shows through, scaled by `255 * 255`.
+        let under = bottom_alpha * (255 - top_alpha);
+        let alpha = top_alpha * 255 + under;
+        if alpha == 0 {
+            return Rgba::TRANSPARENT;
+        }
+        let channel = |top: u8, bottom: u8| {
+            let weighted = u32::from(top) * top_alpha * 255 + u32::from(bottom) * under;
+            ((weighted + alpha / 2) / alpha) as u8
+        };
+        Rgba {
+            color: PixelColor {
+                red: channel(self.color.red, bottom.color.red),
+                green: channel(self.color.green, bottom.color.green),
+                blue: channel(self.color.blue, bottom.color.blue),
+            },
+            alpha: ((alpha + 127) / 255) as u8,
+        }
+    }
+
+    /// Flatten this color onto an opaque `bg` color.
+    pub fn to_pixel_color(&self, bg: PixelColor) -> PixelColor {
+        self.over(Rgba::from(bg)).color
+    }
+}
+
+impl From<PixelColor> for Rgba {
+    /// Create a fully opaque color.
+    fn from(color: PixelColor) -> Self {
+        Rgba { color, alpha: 255 }
+    }
+}
+
+// Thresholds of the `4x4` Bayer matrix for ordered dithering, in the `0..16` range.
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+// The RGB565 field of every 8-bit channel value, already shifted into place, to encode
+// colors in `Rgb565::from_rgb` without bit math in tight loops.
+// Red field: `(value >> 3) << 11`.
+static RGB565_RED: [u16; 256] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x0800, 0x0800, 0x0800,
+    0x0800, 0x0800, 0x0800, 0x0800, 0x1000, 0x1000, 0x1000, 0x1000, 0x1000, 0x1000, 0x1000, 0x1000,
+    0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x1800, 0x2000, 0x2000, 0x2000, 0x2000,
+    0x2000, 0x2000, 0x2000, 0x2000, 0x2800, 0x2800, 0x2800, 0x2800, 0x2800, 0x2800, 0x2800, 0x2800,
+    0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3000, 0x3800, 0x3800, 0x3800, 0x3800,
+    0x3800, 0x3800, 0x3800, 0x3800, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000, 0x4000,
+    0x4800, 0x4800, 0x4800, 0x4800, 0x4800, 0x4800, 0x4800, 0x4800, 0x5000, 0x5000, 0x5000, 0x5000,
+    0x5000, 0x5000, 0x5000, 0x5000, 0x5800, 0x5800, 0x5800, 0x5800, 0x5800, 0x5800, 0x5800, 0x5800,
+    0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6000, 0x6800, 0x6800, 0x6800, 0x6800,
+    0x6800, 0x6800, 0x6800, 0x6800, 0x7000, 0x7000, 0x7000, 0x7000, 0x7000, 0x7000, 0x7000, 0x7000,
+    0x7800, 0x7800, 0x7800, 0x7800, 0x7800, 0x7800, 0x7800, 0x7800, 0x8000, 0x8000, 0x8000, 0x8000,
+    0x8000, 0x8000, 0x8000, 0x8000, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800, 0x8800,
+    0x9000, 0x9000, 0x9000, 0x9000, 0x9000, 0x9000, 0x9000, 0x9000, 0x9800, 0x9800, 0x9800, 0x9800,
+    0x9800, 0x9800, 0x9800, 0x9800, 0xA000, 0xA000, 0xA000, 0xA000, 0xA000, 0xA000, 0xA000, 0xA000,
+    0xA800, 0xA800, 0xA800, 0xA800, 0xA800, 0xA800, 0xA800, 0xA800, 0xB000, 0xB000, 0xB000, 0xB000,
+    0xB000, 0xB000, 0xB000, 0xB000, 0xB800, 0xB800, 0xB800, 0xB800, 0xB800, 0xB800, 0xB800, 0xB800,
+    0xC000, 0xC000, 0xC000, 0xC000, 0xC000, 0xC000, 0xC000, 0xC000, 0xC800, 0xC800, 0xC800, 0xC800,
+    0xC800, 0xC800, 0xC800, 0xC800, 0xD000, 0xD000, 0xD000, 0xD000, 0xD000, 0xD000, 0xD000, 0xD000,
+    0xD800, 0xD800, 0xD800, 0xD800, 0xD800, 0xD800, 0xD800, 0xD800, 0xE000, 0xE000, 0xE000, 0xE000,
+    0xE000, 0xE000, 0xE000, 0xE000, 0xE800, 0xE800, 0xE800, 0xE800, 0xE800, 0xE800, 0xE800, 0xE800,
+    0xF000, 0xF000, 0xF000, 0xF000, 0xF000, 0xF000, 0xF000, 0xF000, 0xF800, 0xF800, 0xF800, 0xF800,
+    0xF800, 0xF800, 0xF800, 0xF800,
+];
+
+// Green field: `(value >> 2) << 5`.
+static RGB565_GREEN: [u16; 256] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0020, 0x0020, 0x0020, 0x0020, 0x0040, 0x0040, 0x0040, 0x0040,
+    0x0060, 0x0060, 0x0060, 0x0060, 0x0080, 0x0080, 0x0080, 0x0080, 0x00A0, 0x00A0, 0x00A0, 0x00A0,
+    0x00C0, 0x00C0, 0x00C0, 0x00C0, 0x00E0, 0x00E0, 0x00E0, 0x00E0, 0x0100, 0x0100, 0x0100, 0x0100,
+    0x0120, 0x0120, 0x0120, 0x0120, 0x0140, 0x0140, 0x0140, 0x0140, 0x0160, 0x0160, 0x0160, 0x0160,
+    0x0180, 0x0180, 0x0180, 0x0180, 0x01A0, 0x01A0, 0x01A0, 0x01A0, 0x01C0, 0x01C0, 0x01C0, 0x01C0,
+    0x01E0, 0x01E0, 0x01E0, 0x01E0, 0x0200, 0x0200, 0x0200, 0x0200, 0x0220, 0x0220, 0x0220, 0x0220,
+    0x0240, 0x0240, 0x0240, 0x0240, 0x0260, 0x0260, 0x0260, 0x0260, 0x0280, 0x0280, 0x0280, 0x0280,
+    0x02A0, 0x02A0, 0x02A0, 0x02A0, 0x02C0, 0x02C0, 0x02C0, 0x02C0, 0x02E0, 0x02E0, 0x02E0, 0x02E0,
+    0x0300, 0x0300, 0x0300, 0x0300, 0x0320, 0x0320, 0x0320, 0x0320, 0x0340, 0x0340, 0x0340, 0x0340,
+    0x0360, 0x0360, 0x0360, 0x0360, 0x0380, 0x0380, 0x0380, 0x0380, 0x03A0, 0x03A0, 0x03A0, 0x03A0,
+    0x03C0, 0x03C0, 0x03C0, 0x03C0, 0x03E0, 0x03E0, 0x03E0, 0x03E0, 0x0400, 0x0400, 0x0400, 0x0400,
+    0x0420, 0x0420, 0x0420, 0x0420, 0x0440, 0x0440, 0x0440, 0x0440, 0x0460, 0x0460, 0x0460, 0x0460,
+    0x0480, 0x0480, 0x0480, 0x0480, 0x04A0, 0x04A0, 0x04A0, 0x04A0, 0x04C0, 0x04C0, 0x04C0, 0x04C0,
+    0x04E0, 0x04E0, 0x04E0, 0x04E0, 0x0500, 0x0500, 0x0500, 0x0500, 0x0520, 0x0520, 0x0520, 0x0520,
+    0x0540, 0x0540, 0x0540, 0x0540, 0x0560, 0x0560, 0x0560, 0x0560, 0x0580, 0x0580, 0x0580, 0x0580,
+    0x05A0, 0x05A0, 0x05A0, 0x05A0, 0x05C0, 0x05C0, 0x05C0, 0x05C0, 0x05E0, 0x05E0, 0x05E0, 0x05E0,
+    0x0600, 0x0600, 0x0600, 0x0600, 0x0620, 0x0620, 0x0620, 0x0620, 0x0640, 0x0640, 0x0640, 0x0640,
+    0x0660, 0x0660, 0x0660, 0x0660, 0x0680, 0x0680, 0x0680, 0x0680, 0x06A0, 0x06A0, 0x06A0, 0x06A0,
+    0x06C0, 0x06C0, 0x06C0, 0x06C0, 0x06E0, 0x06E0, 0x06E0, 0x06E0, 0x0700, 0x0700, 0x0700, 0x0700,
+    0x0720, 0x0720, 0x0720, 0x0720, 0x0740, 0x0740, 0x0740, 0x0740, 0x0760, 0x0760, 0x0760, 0x0760,
+    0x0780, 0x0780, 0x0780, 0x0780, 0x07A0, 0x07A0, 0x07A0, 0x07A0, 0x07C0, 0x07C0, 0x07C0, 0x07C0,
+    0x07E0, 0x07E0, 0x07E0, 0x07E0,
+];
+
+// Blue field: `value >> 3`.
+static RGB565_BLUE: [u16; 256] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0001, 0x0001, 0x0001, 0x0001,
+    0x0001, 0x0001, 0x0001, 0x0001, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002, 0x0002,
+    0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0003, 0x0004, 0x0004, 0x0004, 0x0004,
+    0x0004, 0x0004, 0x0004, 0x0004, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005, 0x0005,
+    0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0006, 0x0007, 0x0007, 0x0007, 0x0007,
+    0x0007, 0x0007, 0x0007, 0x0007, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008, 0x0008,
+    0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x0009, 0x000A, 0x000A, 0x000A, 0x000A,
+    0x000A, 0x000A, 0x000A, 0x000A, 0x000B, 0x000B, 0x000B, 0x000B, 0x000B, 0x000B, 0x000B, 0x000B,
+    0x000C, 0x000C, 0x000C, 0x000C, 0x000C, 0x000C, 0x000C, 0x000C, 0x000D, 0x000D, 0x000D, 0x000D,
+    0x000D, 0x000D, 0x000D, 0x000D, 0x000E, 0x000E, 0x000E, 0x000E, 0x000E, 0x000E, 0x000E, 0x000E,
+    0x000F, 0x000F, 0x000F, 0x000F, 0x000F, 0x000F, 0x000F, 0x000F, 0x0010, 0x0010, 0x0010, 0x0010,
+    0x0010, 0x0010, 0x0010, 0x0010, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011, 0x0011,
+    0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0012, 0x0013, 0x0013, 0x0013, 0x0013,
+    0x0013, 0x0013, 0x0013, 0x0013, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014, 0x0014,
+    0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0015, 0x0016, 0x0016, 0x0016, 0x0016,
+    0x0016, 0x0016, 0x0016, 0x0016, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017, 0x0017,
+    0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0018, 0x0019, 0x0019, 0x0019, 0x0019,
+    0x0019, 0x0019, 0x0019, 0x0019, 0x001A, 0x001A, 0x001A, 0x001A, 0x001A, 0x001A, 0x001A, 0x001A,
+    0x001B, 0x001B, 0x001B, 0x001B, 0x001B, 0x001B, 0x001B, 0x001B, 0x001C, 0x001C, 0x001C, 0x001C,
+    0x001C, 0x001C, 0x001C, 0x001C, 0x001D, 0x001D, 0x001D, 0x001D, 0x001D, 0x001D, 0x001D, 0x001D,
+    0x001E, 0x001E, 0x001E, 0x001E, 0x001E, 0x001E, 0x001E, 0x001E, 0x001F, 0x001F, 0x001F, 0x001F,
+    0x001F, 0x001F, 0x001F, 0x001F,
+];
+
 /// RGB color stored as 16-bit digit, using RGB565 encoding/decoding.
 ///
 /// ```
@@ -195,11 +471,28 @@ pub struct Rgb565(u16);
 impl Rgb565 {
     /// Create `Rgb565` instance from red, green, and blue `u8` values.
     pub fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
-        let r = u16::from((red >> 3) & 0x1F);
-        let g = u16::from((green >> 2) & 0x3F);
-        let b = u16::from((blue >> 3) & 0x1F);
-        let rgb = (r << 11) + (g << 5) + b;
-        Rgb565(rgb)
+        Rgb565(
+            RGB565_RED[usize::from(red)]
+                | RGB565_GREEN[usize::from(green)]
+                | RGB565_BLUE[usize::from(blue)],
+        )
+    }
+
+    /// Create `Rgb565` instance from red, green, and blue `u8` values, with ordered
+    /// dithering for the pixel at `pos`, in row-major order on the `8x8` screen.
+    ///
+    /// Each channel is nudged by a threshold from a `4x4` Bayer matrix before it is
+    /// truncated, so that smooth gradients are spread over neighbouring RGB565 values
+    /// instead of flat bands.
+    pub fn from_rgb_dithered(red: u8, green: u8, blue: u8, pos: usize) -> Self {
+        let threshold = BAYER_4X4[(pos / 8) % 4][(pos % 8) % 4];
+        // 5-bit channels drop 3 bits, and the 6-bit green channel drops 2 bits.
+        let (five, six) = (threshold / 2, threshold / 4);
+        Rgb565::from_rgb(
+            red.saturating_add(five),
+            green.saturating_add(six),
+            blue.saturating_add(five),
+        )
     }
 
     /// Create `(u8, u8, u8)` instance from a `Rgb565` instance.
@@ -210,6 +503,13 @@ impl Rgb565 {
         (red, green, blue)
     }
 
+    /// Create `(u8, u8, u8)` instance from a `Rgb565` instance, replicating the high bits of
+    /// each channel into its low bits, so that the full 5-bit, or 6-bit, value maps to `0xFF`.
+    pub fn to_rgb_expanded(self) -> (u8, u8, u8) {
+        let (red, green, blue) = self.to_rgb();
+        (red | red >> 5, green | green >> 6, blue | blue >> 5)
+    }
+
     #[cfg(not(feature = "big-endian"))]
     // Create `Rgb565` from a pair of little-endian bytes.
     fn from_le(bytes: [u8; 2]) -> Self {
@@ -328,6 +628,178 @@ pub trait StrokeColor {
 mod tests {
     use super::*;
 
+    #[test]
+    fn rgb565_lookup_tables_match_the_bit_math() {
+        for value in 0..=255u8 {
+            let expected_red = u16::from((value >> 3) & 0x1F) << 11;
+            let expected_green = u16::from((value >> 2) & 0x3F) << 5;
+            let expected_blue = u16::from((value >> 3) & 0x1F);
+            let red: u16 = Rgb565::from_rgb(value, 0, 0).into();
+            let green: u16 = Rgb565::from_rgb(0, value, 0).into();
+            let blue: u16 = Rgb565::from_rgb(0, 0, value).into();
+            assert_eq!(red, expected_red);
+            assert_eq!(green, expected_green);
+            assert_eq!(blue, expected_blue);
+            let all: u16 = Rgb565::from_rgb(value, value, value).into();
+            assert_eq!(all, expected_red + expected_green + expected_blue);
+        }
+    }
+
+    #[test]
+    fn rgb565_dithering_spreads_a_gradient_over_more_values() {
+        let distinct = |convert: &dyn Fn(u8, usize) -> Rgb565| {
+            let mut values: Vec<u16> = (0..64)
+                .map(|pos| convert((pos / 2) as u8, pos).into())
+                .collect();
+            values.sort();
+            values.dedup();
+            values.len()
+        };
+        let plain = distinct(&|red, _| Rgb565::from_rgb(red, 0, 0));
+        let dithered = distinct(&|red, pos| Rgb565::from_rgb_dithered(red, 0, 0, pos));
+        assert!(dithered > plain, "{} <= {}", dithered, plain);
+        assert_eq!(
+            Rgb565::from_rgb_dithered(0xFF, 0xFF, 0xFF, 63),
+            Rgb565::from_rgb(0xFF, 0xFF, 0xFF)
+        );
+    }
+
+    #[test]
+    fn color_pixel_dimmed_perceptually_is_darker_than_linearly() {
+        let white = PixelColor::WHITE;
+        assert_eq!(white.dim(0.5), PixelColor::new(127, 127, 127));
+        // half of the perceived brightness is about 21% of the light
+        assert_eq!(white.dim_perceptual(0.5), PixelColor::new(54, 54, 54));
+        assert_eq!(white.dim_perceptual(1.0), white);
+        assert_eq!(white.dim_perceptual(-1.0), PixelColor::BLACK);
+        let steps: Vec<u8> = (0..=4)
+            .map(|step| white.dim_perceptual(step as f32 / 4.0).red)
+            .collect();
+        assert!(steps.windows(3).all(|w| w[2] - w[1] > w[1] - w[0]));
+    }
+
+    #[test]
+    fn opaque_rgba_over_another_color_is_unchanged() {
+        let top = Rgba::new(PixelColor::RED, 255);
+        assert_eq!(top.over(Rgba::new(PixelColor::BLUE, 128)), top);
+        assert_eq!(top.to_pixel_color(PixelColor::BLUE), PixelColor::RED);
+    }
+
+    #[test]
+    fn transparent_rgba_over_another_color_shows_the_bottom() {
+        let bottom = Rgba::new(PixelColor::BLUE, 128);
+        assert_eq!(Rgba::new(PixelColor::RED, 0).over(bottom), bottom);
+        assert_eq!(Rgba::TRANSPARENT.over(Rgba::TRANSPARENT), Rgba::TRANSPARENT);
+        assert_eq!(
+            Rgba::TRANSPARENT.to_pixel_color(PixelColor::GREEN),
+            PixelColor::GREEN
+        );
+    }
+
+    #[test]
+    fn half_transparent_rgba_is_blended_with_the_bottom() {
+        let top = Rgba::new(PixelColor::RED, 128);
+        assert_eq!(
+            top.to_pixel_color(PixelColor::BLUE),
+            PixelColor::new(128, 0, 127)
+        );
+        let blended = top.over(Rgba::new(PixelColor::BLUE, 128));
+        assert_eq!(blended.alpha, 192);
+        assert_eq!(blended.color, PixelColor::new(170, 0, 85));
+    }
+
+    #[test]
+    fn color_pixel_is_linearly_interpolated() {
+        let start = PixelColor::new(0, 100, 200);
+        let end = PixelColor::new(200, 100, 0);
+        assert_eq!(start.lerp(&end, 0.0), start);
+        assert_eq!(start.lerp(&end, 1.0), end);
+        assert_eq!(start.lerp(&end, 0.5), PixelColor::new(100, 100, 100));
+        assert_eq!(start.lerp(&end, -1.0), start);
+        assert_eq!(start.lerp(&end, 2.0), end);
+    }
+
+    #[test]
+    fn color_pixel_multiplied_by_white_is_unchanged() {
+        let color = PixelColor::new(0x12, 0x9A, 0xCD);
+        assert_eq!(color.multiply(&PixelColor::WHITE), color);
+        assert_eq!(color.multiply(&PixelColor::BLACK), PixelColor::BLACK);
+        assert_eq!(
+            PixelColor::new(128, 255, 64).multiply(&PixelColor::new(128, 128, 128)),
+            PixelColor::new(64, 128, 32)
+        );
+    }
+
+    #[test]
+    fn color_pixel_screened_with_black_is_unchanged() {
+        let color = PixelColor::new(0x12, 0x9A, 0xCD);
+        assert_eq!(color.screen(&PixelColor::BLACK), color);
+        assert_eq!(color.screen(&PixelColor::WHITE), PixelColor::WHITE);
+        assert_eq!(
+            PixelColor::new(128, 0, 255).screen(&PixelColor::new(128, 128, 128)),
+            PixelColor::new(192, 128, 255)
+        );
+    }
+
+    #[test]
+    fn color_pixel_is_converted_to_hsv() {
+        assert_eq!(PixelColor::RED.to_hsv(), (0.0, 1.0, 1.0));
+        assert_eq!(PixelColor::GREEN.to_hsv(), (120.0, 1.0, 1.0));
+        assert_eq!(PixelColor::BLUE.to_hsv(), (240.0, 1.0, 1.0));
+        assert_eq!(PixelColor::BLACK.to_hsv(), (0.0, 0.0, 0.0));
+        assert_eq!(PixelColor::WHITE.to_hsv(), (0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn color_pixel_is_created_from_hsv() {
+        assert_eq!(PixelColor::from_hsv(0.0, 1.0, 1.0), PixelColor::RED);
+        assert_eq!(PixelColor::from_hsv(480.0, 1.0, 1.0), PixelColor::GREEN);
+        assert_eq!(PixelColor::from_hsv(-120.0, 1.0, 1.0), PixelColor::BLUE);
+        assert_eq!(PixelColor::from_hsv(60.0, 1.0, 1.0), PixelColor::YELLOW);
+        let color = PixelColor::new(0x12, 0x9A, 0xCD);
+        let (hue, saturation, value) = color.to_hsv();
+        assert_eq!(PixelColor::from_hsv(hue, saturation, value), color);
+    }
+
+    #[test]
+    fn color_pixels_are_approx_equal_after_rgb565_quantization() {
+        assert!(PixelColor::WHITE.approx_eq(&PixelColor::new(0xF8, 0xFC, 0xF8)));
+        assert!(!PixelColor::WHITE.approx_eq(&PixelColor::new(0xF0, 0xFC, 0xF8)));
+    }
+
+    #[test]
+    fn rgb565_expands_channels_to_full_8_bit_range() {
+        assert_eq!(
+            Rgb565::from_rgb(0xFF, 0xFF, 0xFF).to_rgb_expanded(),
+            (0xFF, 0xFF, 0xFF)
+        );
+        assert_eq!(Rgb565::from_rgb(0, 0, 0).to_rgb_expanded(), (0, 0, 0));
+        assert_eq!(
+            Rgb565::from_rgb(0x80, 0x80, 0x80).to_rgb_expanded(),
+            (0x84, 0x82, 0x84)
+        );
+    }
+
+    #[test]
+    fn color_pixel_contrasting_picks_white_over_dark_backgrounds() {
+        assert_eq!(PixelColor::BLACK.contrasting(), PixelColor::WHITE);
+        assert_eq!(PixelColor::BLUE.contrasting(), PixelColor::WHITE);
+        assert_eq!(
+            PixelColor::new(0x20, 0x20, 0x40).contrasting(),
+            PixelColor::WHITE
+        );
+    }
+
+    #[test]
+    fn color_pixel_contrasting_picks_black_over_light_backgrounds() {
+        assert_eq!(PixelColor::WHITE.contrasting(), PixelColor::BLACK);
+        assert_eq!(PixelColor::YELLOW.contrasting(), PixelColor::BLACK);
+        assert_eq!(
+            PixelColor::new(0xC0, 0xC0, 0xC0).contrasting(),
+            PixelColor::BLACK
+        );
+    }
+
     #[cfg(not(feature = "big-endian"))]
     #[test]
     fn color_pixel_encodes_rgb_into_2_bytes_rgb565_with_losses() {
@@ -0,0 +1,76 @@
+//! Easing functions for animation timing
+/// Reshapes the progress of an animation, from `0.0` at the start to `1.0` at the end.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slow, and accelerates.
+    EaseInQuad,
+    /// Starts fast, and decelerates.
+    EaseOutQuad,
+    /// Starts slow, accelerates, and decelerates at the end.
+    EaseInOutCubic,
+}
+
+impl Easing {
+    /// Returns the eased progress for `t`, which is clamped to the `0.0..=1.0` range. The
+    /// result is `0.0` when `t` is `0.0`, and `1.0` when `t` is `1.0`.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.max(0.0).min(1.0);
+        match *self {
+            Easing::Linear => t,
+            Easing::EaseInQuad => t * t,
+            Easing::EaseOutQuad => t * (2.0 - t),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let u = 2.0 - 2.0 * t;
+                    1.0 - u * u * u / 2.0
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Easing; 4] = [
+        Easing::Linear,
+        Easing::EaseInQuad,
+        Easing::EaseOutQuad,
+        Easing::EaseInOutCubic,
+    ];
+
+    #[test]
+    fn easings_start_at_zero_and_end_at_one() {
+        for easing in ALL.iter() {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn ease_in_out_cubic_is_symmetric_around_the_middle() {
+        let easing = Easing::EaseInOutCubic;
+        assert_eq!(easing.apply(0.5), 0.5);
+        assert!((easing.apply(0.25) + easing.apply(0.75) - 1.0).abs() < 1e-6);
+        assert!(easing.apply(0.25) < 0.25);
+    }
+
+    #[test]
+    fn ease_in_is_slower_than_ease_out() {
+        assert_eq!(Easing::EaseInQuad.apply(0.5), 0.25);
+        assert_eq!(Easing::EaseOutQuad.apply(0.5), 0.75);
+    }
+
+    #[test]
+    fn easing_clamps_progress() {
+        for easing in ALL.iter() {
+            assert_eq!(easing.apply(-1.0), 0.0);
+            assert_eq!(easing.apply(2.0), 1.0);
+        }
+    }
+}
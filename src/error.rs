@@ -1,6 +1,9 @@
 //! Errors for the SenseHat Screen.
 #[cfg(feature = "linux-framebuffer")]
 use framebuffer::FramebufferError;
+#[cfg(feature = "image-support")]
+use png::EncodingError;
+use std::io;
 #[cfg(feature = "fonts")]
 use std::string::FromUtf16Error;
 
@@ -9,8 +12,40 @@ use std::string::FromUtf16Error;
 pub enum ScreenError {
     #[cfg(feature = "linux-framebuffer")]
     Framebuffer(FramebufferError),
+    #[cfg(feature = "linux-framebuffer")]
+    InvalidGammaTable,
+    #[cfg(feature = "linux-framebuffer")]
+    VsyncUnsupported,
+    #[cfg(feature = "linux-framebuffer")]
+    FramebufferTooSmall {
+        len: usize,
+    },
+    #[cfg(feature = "linux-framebuffer")]
+    UnexpectedGeometry {
+        width: u32,
+        height: u32,
+    },
     #[cfg(feature = "fonts")]
     Unicode(FromUtf16Error),
+    #[cfg(feature = "base64")]
+    InvalidBase64,
+    /// An I/O error, from reading and writing files, or from device control calls to the
+    /// framebuffer.
+    Io(io::Error),
+    #[cfg(feature = "scroll")]
+    ScrollTooShort,
+    InvalidAsciiArt,
+    InvalidPbm,
+    InvalidPixelCount {
+        len: usize,
+    },
+    #[cfg(feature = "image-support")]
+    Png(EncodingError),
+    #[cfg(feature = "ndarray-support")]
+    InvalidArrayShape {
+        rows: usize,
+        columns: usize,
+    },
 }
 
 #[cfg(feature = "linux-framebuffer")]
@@ -20,9 +55,39 @@ impl From<FramebufferError> for ScreenError {
     }
 }
 
+impl From<io::Error> for ScreenError {
+    fn from(err: io::Error) -> ScreenError {
+        ScreenError::Io(err)
+    }
+}
+
+#[cfg(feature = "image-support")]
+impl From<EncodingError> for ScreenError {
+    fn from(err: EncodingError) -> ScreenError {
+        ScreenError::Png(err)
+    }
+}
+
 #[cfg(feature = "fonts")]
 impl From<FromUtf16Error> for ScreenError {
     fn from(err: FromUtf16Error) -> ScreenError {
         ScreenError::Unicode(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_errors_convert_into_screen_errors() {
+        fn read_frame() -> Result<(), ScreenError> {
+            Err(io::Error::new(io::ErrorKind::UnexpectedEof, "short frame"))?;
+            Ok(())
+        }
+        match read_frame() {
+            Err(ScreenError::Io(err)) => assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof),
+            other => panic!("expected an I/O error, got {:?}", other),
+        }
+    }
+}
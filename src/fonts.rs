@@ -1,10 +1,13 @@
 //! 8x8 font collection
 use super::{
     color::{BackgroundColor, StrokeColor},
+    frame::outline_bits,
     FrameLine, PixelColor, PixelFrame,
 };
 
 use super::error::ScreenError;
+#[cfg(feature = "scroll")]
+use super::scroll::Scroll;
 pub use font8x8::{
     FontUnicode, UnicodeFonts, BASIC_FONTS, BLOCK_FONTS, BOX_FONTS, GREEK_FONTS, HIRAGANA_FONTS,
     LATIN_FONTS,
@@ -24,7 +27,7 @@ lazy_static! {
     ///
     /// `FONT_COLLECTION.get(font: char)` returns the low-level `FontUnicode` if the font
     /// is found in the collection.
-    pub static ref FONT_COLLECTION: FontCollection = FontCollection(default_hashmap());
+    pub static ref FONT_COLLECTION: FontCollection = FontCollection::new();
 }
 
 fn default_hashmap() -> HashMap<char, FontUnicode> {
@@ -39,22 +42,27 @@ fn default_hashmap() -> HashMap<char, FontUnicode> {
         .collect()
 }
 
+// Number of blank columns between glyphs in proportional layouts.
+const PROPORTIONAL_SPACING: i16 = 1;
+// Width, in columns, of glyphs without lit pixels (e.g. whitespace) in proportional layouts.
+const PROPORTIONAL_BLANK_WIDTH: i16 = 3;
+
 // A set of font symbols that can be printed on a `Screen`.
 #[derive(Clone, Debug, PartialEq)]
 //#[cfg_attr(feature = "serde-support", derive(Serialize, Deserialize))]
-pub struct FontCollection(HashMap<char, FontUnicode>);
+pub struct FontCollection(HashMap<char, FontUnicode>, HashMap<(char, char), i8>);
 
 impl FontCollection {
     /// Create a default `FontCollection`, containing the Unicode constants
     /// from the [font8x8](https://github.com/saibatizoku/font8x8-rs) crate, except for
     /// `MISC_FONTS`, and `SGA_FONTS` (which are non-standard).
     pub fn new() -> Self {
-        FontCollection(default_hashmap())
+        FontCollection(default_hashmap(), HashMap::new())
     }
 
     /// Create a `FontCollection` with a custom HashMap of font symbols.
     pub fn from_hashmap(hashmap: HashMap<char, FontUnicode>) -> Self {
-        FontCollection(hashmap)
+        FontCollection(hashmap, HashMap::new())
     }
 
     /// Get an `Option` with the symbol's byte rendering.
@@ -67,6 +75,153 @@ impl FontCollection {
         self.0.contains_key(&symbol)
     }
 
+    /// Returns an iterator over the unicode keys of every symbol in the collection, in no
+    /// particular order.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.0.keys().cloned()
+    }
+
+    /// Return the number of symbols in the collection.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the collection has no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Merge the symbols from `other` into this collection. Symbols from `other` replace those
+    /// with the same unicode key.
+    pub fn merge(&mut self, other: &FontCollection) {
+        self.0
+            .extend(other.0.iter().map(|(&key, &font)| (key, font)));
+        self.1
+            .extend(other.1.iter().map(|(&pair, &adjust)| (pair, adjust)));
+    }
+
+    /// Create a new `FontCollection` with the symbols from this, and an `other` collection.
+    /// Symbols from `other` replace those with the same unicode key.
+    pub fn union(mut self, other: FontCollection) -> FontCollection {
+        self.0.extend(other.0);
+        self.1.extend(other.1);
+        self
+    }
+
+    /// Set the kerning adjustment, in columns, between the `left` and `right` symbols, when
+    /// rendering proportional text. Negative values bring the symbols closer together.
+    pub fn set_kerning(&mut self, left: char, right: char, adjust: i8) {
+        if adjust == 0 {
+            self.1.remove(&(left, right));
+        } else {
+            self.1.insert((left, right), adjust);
+        }
+    }
+
+    /// Get the kerning adjustment, in columns, between the `left` and `right` symbols. Defaults
+    /// to `0`.
+    pub fn kerning(&self, left: char, right: char) -> i8 {
+        self.1.get(&(left, right)).cloned().unwrap_or(0)
+    }
+
+    /// Render a `FontString` as proportional text, returning its columns from left to right.
+    ///
+    /// Each glyph is trimmed to its lit columns, and glyphs are separated by a single column of
+    /// `bg` color, adjusted by the kerning between each pair of symbols. Glyphs without lit
+    /// pixels, such as whitespace, are three columns wide.
+    pub fn proportional_columns(
+        &self,
+        text: &FontString,
+        stroke: PixelColor,
+        bg: PixelColor,
+    ) -> Vec<[PixelColor; 8]> {
+        let mut columns: Vec<[PixelColor; 8]> = Vec::new();
+        let mut previous: Option<char> = None;
+        for font in text.0.iter() {
+            let glyph = FontFrame::new(*font, stroke, bg).pixel_frame().as_columns();
+            let (start, end) = glyph_column_span(font.byte_array());
+            let mut cursor = columns.len() as i16;
+            if let Some(left) = previous {
+                cursor += PROPORTIONAL_SPACING + i16::from(self.kerning(left, font.char()));
+                cursor = cursor.max(0);
+            }
+            for (offset, glyph_col) in glyph[start..end].iter().enumerate() {
+                let idx = cursor as usize + offset;
+                if idx < columns.len() {
+                    // overlapping columns keep the stroke of both glyphs
+                    for (px, &glyph_px) in columns[idx].iter_mut().zip(glyph_col.iter()) {
+                        if glyph_px != bg {
+                            *px = glyph_px;
+                        }
+                    }
+                } else {
+                    columns.resize(idx, [bg; 8]);
+                    columns.push(*glyph_col);
+                }
+            }
+            if start == end {
+                let blank_end = (cursor + PROPORTIONAL_BLANK_WIDTH) as usize;
+                if blank_end > columns.len() {
+                    columns.resize(blank_end, [bg; 8]);
+                }
+            }
+            previous = Some(font.char());
+        }
+        columns
+    }
+
+    /// Render a `FontString` as pages of proportional text, meant to be shown one at a time.
+    ///
+    /// Each page holds up to `width_glyphs` glyphs, as long as they fit in its 8 columns, and
+    /// is left-aligned over the `bg` color. A glyph that does not fit on the current page
+    /// starts the next one.
+    ///
+    /// # Panics
+    /// If `width_glyphs` is `0`.
+    pub fn paginate(
+        &self,
+        text: &FontString,
+        width_glyphs: usize,
+        stroke: PixelColor,
+        bg: PixelColor,
+    ) -> Vec<PixelFrame> {
+        assert!(width_glyphs > 0);
+        let mut chunks: Vec<FontString> = Vec::new();
+        let mut page = FontString::new();
+        for font in text.0.iter() {
+            page.0.push(*font);
+            if page.0.len() > 1
+                && (page.0.len() > width_glyphs || self.proportional_width(&page) > 8)
+            {
+                page.0.pop();
+                chunks.push(page);
+                page = FontString(vec![*font]);
+            }
+        }
+        if !page.0.is_empty() {
+            chunks.push(page);
+        }
+        chunks
+            .iter()
+            .map(|chunk| {
+                let mut columns = [[bg; 8]; 8];
+                for (column, rendered) in columns
+                    .iter_mut()
+                    .zip(self.proportional_columns(chunk, stroke, bg))
+                {
+                    *column = rendered;
+                }
+                PixelFrame::from_columns(&columns)
+            })
+            .collect()
+    }
+
+    /// Return the width, in columns, of a `FontString` rendered as proportional text.
+    pub fn proportional_width(&self, text: &FontString) -> usize {
+        self.proportional_columns(text, PixelColor::WHITE, PixelColor::BLACK)
+            .len()
+    }
+
     /// Sanitize a `&str` and create a new `FontString`.
     pub fn sanitize_str(&self, s: &str) -> Result<FontString, ScreenError> {
         let valid = s
@@ -84,6 +239,18 @@ impl Default for FontCollection {
     }
 }
 
+// Returns the range of columns, `start..end`, with lit pixels in a font symbol. Symbols without
+// lit pixels return an empty range.
+fn glyph_column_span(symbol: [u8; 8]) -> (usize, usize) {
+    let lit = symbol.iter().fold(0u8, |lit, row| lit | row);
+    if lit == 0 {
+        return (0, 0);
+    }
+    let start = lit.trailing_zeros() as usize;
+    let end = 8 - lit.leading_zeros() as usize;
+    (start, end)
+}
+
 /// A `FontString` is a collection of `FontUnicode` which can be rendered to frames for the LED
 /// Matrix.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -115,6 +282,73 @@ impl FontString {
             .map(|f| f.into())
             .collect::<Vec<PixelFrame>>()
     }
+
+    /// Consume the font string, and render each inner font into a frame of a `Scroll`.
+    ///
+    /// Returns `ScreenError::ScrollTooShort` if the font string has less than 2 fonts.
+    #[cfg(feature = "scroll")]
+    pub fn into_scroll(self, stroke: PixelColor, bg: PixelColor) -> Result<Scroll, ScreenError> {
+        if self.0.len() < 2 {
+            return Err(ScreenError::ScrollTooShort);
+        }
+        let frames = self
+            .0
+            .into_iter()
+            .map(|font| FontFrame::new(font, stroke, bg).pixel_frame())
+            .collect();
+        Ok(Scroll::from_vec(frames))
+    }
+
+    /// Returns a `Vec<PixelFrame>` for each inner font, with a stroke color that is linearly
+    /// interpolated from `start`, on the first font, to `end`, on the last font.
+    pub fn pixel_frames_gradient(
+        &self,
+        start: PixelColor,
+        end: PixelColor,
+        bg: PixelColor,
+    ) -> Vec<PixelFrame> {
+        let last = self.0.len().saturating_sub(1).max(1) as f32;
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(idx, font)| {
+                let stroke = start.lerp(&end, idx as f32 / last);
+                FontFrame::new(*font, stroke, bg).pixel_frame()
+            })
+            .collect::<Vec<PixelFrame>>()
+    }
+
+    /// Returns a `Vec<PixelFrame>` with one page per inner font, meant to be shown one at a
+    /// time, instead of being scrolled.
+    pub fn pages(&self, stroke: PixelColor, bg: PixelColor) -> Vec<PixelFrame> {
+        self.pixel_frames(stroke, bg)
+    }
+
+    /// Returns a `Vec<PixelFrame>` that types the string like a typewriter. Only one glyph
+    /// fits on the screen, so each glyph is revealed over 8 frames, one column at a time from
+    /// the left, and the last of them shows the whole glyph.
+    pub fn typewriter_frames(&self, stroke: PixelColor, bg: PixelColor) -> Vec<PixelFrame> {
+        self.pixel_frames(stroke, bg)
+            .iter()
+            .flat_map(|glyph| glyph.reveal_columns(bg, true).skip(1))
+            .collect()
+    }
+
+    /// Returns a `Vec<PixelFrame>` for each inner font, followed by the `cursor` frame when
+    /// `blink_on` is `true`. Toggle `blink_on` on a timer to get a blinking cursor.
+    pub fn with_cursor(
+        &self,
+        stroke: PixelColor,
+        bg: PixelColor,
+        cursor: PixelFrame,
+        blink_on: bool,
+    ) -> Vec<PixelFrame> {
+        let mut frames = self.pixel_frames(stroke, bg);
+        if blink_on {
+            frames.push(cursor);
+        }
+        frames
+    }
 }
 
 impl fmt::Display for FontString {
@@ -148,12 +382,75 @@ impl FontFrame {
         }
     }
 
+    /// Create a new font frame with a `background` color, and a stroke color that contrasts
+    /// with it, as given by `PixelColor::contrasting`.
+    pub fn contrasting(font: FontUnicode, background: PixelColor) -> Self {
+        FontFrame::new(font, background.contrasting(), background)
+    }
+
+    /// Consume the font frame, and return it with a new `stroke` color.
+    pub fn with_stroke(mut self, color: PixelColor) -> Self {
+        self.stroke = color;
+        self
+    }
+
+    /// Consume the font frame, and return it with a new `background` color.
+    pub fn with_background(mut self, color: PixelColor) -> Self {
+        self.background = color;
+        self
+    }
+
+    /// The unicode value point of the inner font.
+    pub fn char(&self) -> char {
+        self.font.char()
+    }
+
+    /// The byte rendering of the inner font.
+    pub fn byte_array(&self) -> [u8; 8] {
+        self.font.byte_array()
+    }
+
     /// The `PixelFrame` for this font.
     pub fn pixel_frame(&self) -> PixelFrame {
         let pixels =
             font_to_pixel_color_array_with_bg(self.font.byte_array(), self.stroke, self.background);
         pixels.into()
     }
+
+    /// The `PixelFrame` for this font, underlined with a `color` line on the bottom row.
+    pub fn underline(&self, color: PixelColor) -> PixelFrame {
+        self.decorate_row(7, color)
+    }
+
+    /// The `PixelFrame` for this font, overlined with a `color` line on the top row.
+    pub fn overline(&self, color: PixelColor) -> PixelFrame {
+        self.decorate_row(0, color)
+    }
+
+    /// The `PixelFrame` for this font, struck through with a `color` line across the middle row.
+    pub fn strikethrough(&self, color: PixelColor) -> PixelFrame {
+        self.decorate_row(3, color)
+    }
+
+    /// The `PixelFrame` for this font, with a 1-pixel `halo` around the stroke, so that the
+    /// glyph stands out over any background.
+    pub fn with_halo(&self, halo: PixelColor) -> PixelFrame {
+        let mut frame = self.pixel_frame();
+        let outline = outline_bits(font_to_mono_bits(self.font.byte_array()));
+        for idx in 0..64 {
+            if outline & 1 << idx > 0 {
+                frame[idx] = halo;
+            }
+        }
+        frame
+    }
+
+    // Render the `PixelFrame` for this font, with a horizontal `color` line on the given row.
+    fn decorate_row(&self, row: usize, color: PixelColor) -> PixelFrame {
+        let mut rows = self.pixel_frame().as_rows();
+        rows[row] = [color; 8];
+        PixelFrame::from_rows(&rows)
+    }
 }
 
 impl From<FontFrame> for PixelFrame {
@@ -214,6 +511,11 @@ pub fn font_to_frame(symbol: [u8; 8], color: PixelColor) -> FrameLine {
     FrameLine::from_pixels(&pixels)
 }
 
+/// Render a font symbol into a `u64` monochrome bitmap, suitable for `PixelFrame::from_mono_bits`.
+pub fn font_to_mono_bits(symbol: [u8; 8]) -> u64 {
+    u64::from_le_bytes(symbol)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,6 +584,21 @@ mod tests {
         assert_eq!(format!("{}", invalid_text), "");
     }
 
+    #[cfg(feature = "scroll")]
+    #[test]
+    fn font_string_is_rendered_into_a_scroll_of_one_frame_per_font() {
+        let font_set = FontCollection::new();
+        let text = font_set.sanitize_str("abc").unwrap();
+        let frames = text.pixel_frames(RED, BLK);
+        let scroll = text.into_scroll(RED, BLK).unwrap();
+        assert_eq!(scroll.len(), 3);
+        assert_eq!(scroll.frames(), &frames[..]);
+        match font_set.sanitize_str("a").unwrap().into_scroll(RED, BLK) {
+            Err(ScreenError::ScrollTooShort) => {}
+            other => panic!("expected a short scroll error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn font_collection_gets_optional_symbol_by_unicode_key() {
         let font_set = FontCollection::new();
@@ -296,6 +613,112 @@ mod tests {
         assert!(has_symbol);
     }
 
+    #[test]
+    fn font_collection_iterates_over_supported_chars() {
+        let font_set = FontCollection::new();
+        assert!(font_set.len() > 128);
+        assert_eq!(font_set.chars().count(), font_set.len());
+        assert!(font_set.chars().any(|c| c == 'ñ'));
+    }
+
+    #[test]
+    fn font_collection_is_empty_without_symbols() {
+        let font_set = FontCollection::from_hashmap(HashMap::new());
+        assert!(font_set.is_empty());
+        assert_eq!(font_set.len(), 0);
+        assert!(!FontCollection::new().is_empty());
+    }
+
+    #[test]
+    fn font_collection_merges_symbols_replacing_duplicate_keys() {
+        let custom_a = FontUnicode('A', [0xFF; 8]);
+        let custom_set = FontCollection::from_hashmap(vec![('A', custom_a)].into_iter().collect());
+        let mut font_set = FontCollection::new();
+        let len = font_set.0.len();
+        font_set.merge(&custom_set);
+        assert_eq!(font_set.get('A'), Some(&custom_a));
+        assert_eq!(font_set.0.len(), len);
+    }
+
+    #[test]
+    fn font_collection_union_adds_symbols_replacing_duplicate_keys() {
+        let custom_a = FontUnicode('A', [0xFF; 8]);
+        let custom_set = FontCollection::from_hashmap(vec![('A', custom_a)].into_iter().collect());
+        let basic_set = FontCollection::from_hashmap(
+            BASIC_FONTS.iter().take(0x42).map(|x| (x.0, *x)).collect(),
+        );
+        let font_set = basic_set.union(custom_set);
+        assert_eq!(font_set.get('A'), Some(&custom_a));
+        assert!(font_set.contains_key('@'));
+        assert!(!font_set.contains_key('B'));
+    }
+
+    #[test]
+    fn font_collection_kerning_defaults_to_zero() {
+        let mut font_set = FontCollection::new();
+        assert_eq!(font_set.kerning('A', 'V'), 0);
+        font_set.set_kerning('A', 'V', -1);
+        assert_eq!(font_set.kerning('A', 'V'), -1);
+        assert_eq!(font_set.kerning('V', 'A'), 0);
+        font_set.set_kerning('A', 'V', 0);
+        assert_eq!(font_set, FontCollection::new());
+    }
+
+    #[test]
+    fn font_collection_renders_proportional_columns() {
+        let font_set = FontCollection::new();
+        let text = font_set.sanitize_str("i i").unwrap();
+        let i_font = font_set.get('i').unwrap().byte_array();
+        let (start, end) = glyph_column_span(i_font);
+        let i_width = end - start;
+        let columns = font_set.proportional_columns(&text, PixelColor::RED, PixelColor::BLACK);
+        assert_eq!(columns.len(), i_width + 1 + 3 + 1 + i_width);
+        let i_columns = font_to_pixel_frame(i_font, PixelColor::RED).as_columns();
+        assert_eq!(&columns[..i_width], &i_columns[start..end]);
+        for column in columns[i_width..i_width + 5].iter() {
+            assert_eq!(column, &[PixelColor::BLACK; 8]);
+        }
+        assert_eq!(&columns[i_width + 5..], &i_columns[start..end]);
+    }
+
+    #[test]
+    fn font_collection_kerning_reduces_proportional_width() {
+        let mut font_set = FontCollection::new();
+        let text = font_set.sanitize_str("AV").unwrap();
+        let width = font_set.proportional_width(&text);
+        font_set.set_kerning('A', 'V', -1);
+        assert_eq!(font_set.proportional_width(&text), width - 1);
+    }
+
+    #[test]
+    fn font_collection_paginates_proportional_text() {
+        let font_set = FontCollection::new();
+        let render = |text: &str| {
+            let text = font_set.sanitize_str(text).unwrap();
+            let mut columns = [[BLK; 8]; 8];
+            for (column, rendered) in columns
+                .iter_mut()
+                .zip(font_set.proportional_columns(&text, RED, BLK))
+            {
+                *column = rendered;
+            }
+            PixelFrame::from_columns(&columns)
+        };
+        // each period is 2 columns wide, so that only 3 fit in a page
+        let text = font_set.sanitize_str("....").unwrap();
+        let pages = font_set.paginate(&text, 4, RED, BLK);
+        assert_eq!(pages, vec![render("..."), render(".")]);
+        let pages = font_set.paginate(&text, 2, RED, BLK);
+        assert_eq!(pages, vec![render(".."), render("..")]);
+        // wide glyphs get a page each, whatever the glyph limit
+        let text = font_set.sanitize_str("MW").unwrap();
+        let pages = font_set.paginate(&text, 2, RED, BLK);
+        assert_eq!(pages, vec![render("M"), render("W")]);
+        assert!(font_set
+            .paginate(&FontString::new(), 1, RED, BLK)
+            .is_empty());
+    }
+
     #[test]
     fn font_string_new_method_starts_emtpy_instance() {
         let font_string = FontString::new();
@@ -357,6 +780,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn font_string_pages_returns_a_full_frame_for_each_glyph() {
+        let font_set = FontCollection::new();
+        let font_string = font_set.sanitize_str("Mち┶").unwrap();
+        let pages = font_string.pages(PixelColor::YELLOW, PixelColor::BLACK);
+        let frames = font_string.font_frames(PixelColor::YELLOW, PixelColor::BLACK);
+        assert_eq!(pages.len(), 3);
+        for (page, frame) in pages.iter().zip(frames.iter()) {
+            assert_eq!(*page, frame.pixel_frame());
+        }
+    }
+
+    #[test]
+    fn font_string_gradient_interpolates_the_stroke_color_across_glyphs() {
+        let font_set = FontCollection::new();
+        let font_string = font_set.sanitize_str("abc").unwrap();
+        let start = PixelColor::new(0, 0, 200);
+        let end = PixelColor::new(200, 0, 0);
+        let frames = font_string.pixel_frames_gradient(start, end, PixelColor::BLACK);
+        let fonts = font_string.font_frames(start, PixelColor::BLACK);
+        let middle = PixelColor::new(100, 0, 100);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], fonts[0].pixel_frame());
+        assert_eq!(
+            frames[1],
+            FontFrame::new(fonts[1].font, middle, BLK).pixel_frame()
+        );
+        assert_eq!(
+            frames[2],
+            FontFrame::new(fonts[2].font, end, BLK).pixel_frame()
+        );
+    }
+
+    #[test]
+    fn font_string_typewriter_frames_reveal_each_glyph_column_by_column() {
+        let font_set = FontCollection::new();
+        let font_string = font_set.sanitize_str("type").unwrap();
+        let frames = font_string.typewriter_frames(PixelColor::GREEN, PixelColor::BLACK);
+        let fonts = font_string.font_frames(PixelColor::GREEN, PixelColor::BLACK);
+        assert_eq!(frames.len(), 4 * 8);
+        for (typed, font) in frames.chunks(8).zip(fonts.iter()) {
+            let glyph = font.pixel_frame();
+            let reveal: Vec<PixelFrame> = glyph.reveal_columns(BLK, true).skip(1).collect();
+            assert_eq!(typed, &reveal[..]);
+            assert_eq!(typed[7], glyph);
+        }
+    }
+
+    #[test]
+    fn font_string_with_cursor_appends_the_cursor_frame_when_blink_is_on() {
+        let font_set = FontCollection::new();
+        let font_string = font_set.sanitize_str("ok").unwrap();
+        let cursor = PixelFrame::underscore_cursor(PixelColor::WHITE);
+        let on = font_string.with_cursor(PixelColor::BLUE, PixelColor::BLACK, cursor, true);
+        let off = font_string.with_cursor(PixelColor::BLUE, PixelColor::BLACK, cursor, false);
+        assert_eq!(on.len(), off.len() + 1);
+        assert_eq!(on[2], cursor);
+        assert_eq!(
+            off,
+            font_string.pixel_frames(PixelColor::BLUE, PixelColor::BLACK)
+        );
+    }
+
     #[test]
     fn fn_font_to_pixel_color_array_with_bg_creates_new_array() {
         let font_set = FontCollection::new();
@@ -397,6 +883,27 @@ mod tests {
         assert_eq!(px_frame_line, PixelFrame::from(BOX_FONT).frame_line());
     }
 
+    #[test]
+    fn fn_font_to_mono_bits_renders_the_same_frame_as_font_to_pixel_frame() {
+        let font_set = FontCollection::new();
+        let chi_font = font_set.get('ち').unwrap();
+        let bits = font_to_mono_bits(chi_font.byte_array());
+        assert_eq!(
+            PixelFrame::from_mono_bits(bits, PixelColor::RED, PixelColor::BLACK),
+            PixelFrame::from(HIRAGANA_FONT)
+        );
+    }
+
+    #[test]
+    fn rendered_glyphs_are_packed_back_into_font_bytes() {
+        let font_set = FontCollection::new();
+        for symbol in &['M', 'ち', '┶', 'ñ'] {
+            let font = font_set.get(*symbol).unwrap();
+            let px_frame = font_to_pixel_frame(font.byte_array(), PixelColor::WHITE);
+            assert_eq!(px_frame.to_font_bytes(), font.byte_array());
+        }
+    }
+
     #[test]
     fn font_frames_are_created_from_ut16_font_a_stroke_and_a_background_color() {
         let font_set = FontCollection::new();
@@ -412,6 +919,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn font_frames_are_created_with_a_stroke_contrasting_the_background_color() {
+        let font_set = FontCollection::new();
+        let letter_a = font_set.get('a').unwrap();
+        let font_frame = FontFrame::contrasting(*letter_a, PixelColor::BLUE);
+        assert_eq!(font_frame.get_stroke_color(), PixelColor::WHITE);
+        let font_frame = FontFrame::contrasting(*letter_a, PixelColor::YELLOW);
+        assert_eq!(font_frame.get_stroke_color(), PixelColor::BLACK);
+    }
+
+    #[test]
+    fn font_frames_expose_their_inner_font() {
+        let font_set = FontCollection::new();
+        let letter_m = font_set.get('M').unwrap();
+        let font_frame = FontFrame::new(*letter_m, PixelColor::WHITE, PixelColor::BLACK);
+        assert_eq!(font_frame.char(), 'M');
+        assert_eq!(font_frame.byte_array(), letter_m.byte_array());
+    }
+
     #[test]
     fn font_frames_is_represented_as_a_pixel_frame() {
         let font_set = FontCollection::new();
@@ -421,6 +947,69 @@ mod tests {
         assert_eq!(px_frame, PixelFrame::from(HIRAGANA_FONT));
     }
 
+    #[test]
+    fn font_frames_are_underlined_on_the_bottom_row() {
+        let font_set = FontCollection::new();
+        let hiragana_font = font_set.get('ち').unwrap();
+        let font_frame = FontFrame::new(*hiragana_font, PixelColor::RED, PixelColor::BLACK);
+        let rows = font_frame.underline(PixelColor::GREEN).as_rows();
+        let base_rows = PixelFrame::from(HIRAGANA_FONT).as_rows();
+        assert_eq!(rows[7], [PixelColor::GREEN; 8]);
+        assert_eq!(&rows[..7], &base_rows[..7]);
+    }
+
+    #[test]
+    fn font_frames_are_overlined_on_the_top_row() {
+        let font_set = FontCollection::new();
+        let box_font = font_set.get('┶').unwrap();
+        let font_frame = FontFrame::new(*box_font, PixelColor::GREEN, PixelColor::BLACK);
+        let rows = font_frame.overline(PixelColor::RED).as_rows();
+        let base_rows = PixelFrame::from(BOX_FONT).as_rows();
+        assert_eq!(rows[0], [PixelColor::RED; 8]);
+        assert_eq!(&rows[1..], &base_rows[1..]);
+    }
+
+    #[test]
+    fn font_frames_are_struck_through_on_the_middle_row() {
+        let font_set = FontCollection::new();
+        let letter_m = font_set.get('M').unwrap();
+        let font_frame = FontFrame::new(*letter_m, PixelColor::BLUE, PixelColor::BLACK);
+        let rows = font_frame.strikethrough(PixelColor::RED).as_rows();
+        let base_rows = PixelFrame::from(BASIC_FONT).as_rows();
+        assert_eq!(rows[3], [PixelColor::RED; 8]);
+        assert_eq!(&rows[..3], &base_rows[..3]);
+        assert_eq!(&rows[4..], &base_rows[4..]);
+    }
+
+    #[test]
+    fn font_frames_are_rendered_with_a_halo_around_the_stroke() {
+        let font_set = FontCollection::new();
+        let letter_m = font_set.get('M').unwrap();
+        let font_frame = FontFrame::new(*letter_m, BLU, GRN);
+        let frame = font_frame.with_halo(YLW);
+        let base = PixelFrame::from(BASIC_FONT);
+        for row in 0..8i32 {
+            for col in 0..8i32 {
+                let idx = (row * 8 + col) as usize;
+                let next_to_stroke = (row - 1..=row + 1).any(|r| {
+                    (col - 1..=col + 1).any(|c| match (r, c) {
+                        (0..=7, 0..=7) => base[(r * 8 + c) as usize] == BLU,
+                        _ => false,
+                    })
+                });
+                let expected = if base[idx] == BLU {
+                    BLU
+                } else if next_to_stroke {
+                    YLW
+                } else {
+                    GRN
+                };
+                assert_eq!(frame[idx], expected);
+            }
+        }
+        assert_eq!(frame[56], YLW);
+    }
+
     #[test]
     fn pixel_frame_implements_from_font_frame_trait() {
         let font_set = FontCollection::new();
@@ -470,6 +1059,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn font_frame_colors_are_set_with_chained_builders() {
+        let font_set = FontCollection::new();
+        let letter_a = font_set.get('a').unwrap();
+        let font_frame = FontFrame::new(*letter_a, PixelColor::WHITE, PixelColor::BLACK)
+            .with_stroke(PixelColor::RED)
+            .with_background(PixelColor::BLUE);
+        assert_eq!(
+            font_frame,
+            FontFrame {
+                font: *letter_a,
+                stroke: PixelColor::RED,
+                background: PixelColor::BLUE
+            }
+        );
+    }
+
     #[test]
     fn font_frame_gets_stroke_color() {
         let font_set = FontCollection::new();
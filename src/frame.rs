@@ -1,15 +1,24 @@
 //! Frames for the LED Matrix screen
+#[cfg(feature = "base64")]
+#[path = "frame_base64.rs"]
+pub mod base64;
 #[cfg(feature = "clip")]
 #[path = "frame_clip.rs"]
 pub mod clip;
 #[cfg(feature = "offset")]
 #[path = "frame_offset.rs"]
 pub mod offset;
+#[path = "frame_rate.rs"]
+pub mod rate;
 #[cfg(feature = "rotate")]
 #[path = "frame_rotate.rs"]
 pub mod rotate;
+#[path = "frame_transition.rs"]
+pub mod transition;
 
-use super::color::{PixelColor, Rgb565};
+use super::color::{PixelColor, Rgb565, Rgba};
+use super::error::ScreenError;
+use std::collections::HashMap;
 use std::fmt::{self, Write};
 use std::ops::{Index, IndexMut};
 
@@ -18,6 +27,9 @@ use std::ops::{Index, IndexMut};
 pub struct FrameLine([Rgb565; 64]);
 
 impl FrameLine {
+    /// The number of bytes in a `FrameLine`, two per pixel.
+    pub const BYTE_LEN: usize = 128;
+
     //  Defaults to an empty vector with capacity for 128 bytes.
     fn new() -> Self {
         FrameLine([Rgb565::default(); 64])
@@ -51,6 +63,43 @@ impl FrameLine {
         FrameLine(colors)
     }
 
+    /// Create a new `FrameLine` instance, given a slice of `PixelColor`, with ordered
+    /// dithering to reduce the banding of smooth gradients. See `Rgb565::from_rgb_dithered`.
+    pub fn from_pixels_dithered(pixels: &[PixelColor; 64]) -> Self {
+        let mut colors = [Rgb565::default(); 64];
+        for (pos, (color, px)) in colors.iter_mut().zip(pixels.iter()).enumerate() {
+            *color = Rgb565::from_rgb_dithered(px.red, px.green, px.blue, pos);
+        }
+        FrameLine(colors)
+    }
+
+    /// Create a new `FrameLine` instance, given a slice of `PixelColor`, for panels wired in
+    /// a serpentine pattern, where every odd row is addressed from right to left.
+    pub fn from_pixels_serpentine(pixels: &[PixelColor; 64]) -> Self {
+        let mut serpentine = *pixels;
+        for row in serpentine.chunks_mut(8).skip(1).step_by(2) {
+            row.reverse();
+        }
+        FrameLine::from_pixels(&serpentine)
+    }
+
+    /// Create a new `FrameLine` instance, given a slice of `PixelColor`, for panels mounted
+    /// upside-down, where the pixels are addressed in reverse order.
+    pub fn from_pixels_flipped_180(pixels: &[PixelColor; 64]) -> Self {
+        let mut flipped = *pixels;
+        flipped.reverse();
+        FrameLine::from_pixels(&flipped)
+    }
+
+    /// Create a new `PixelFrame` from the RGB565 colors of this `FrameLine`.
+    pub fn to_pixel_frame(&self) -> PixelFrame {
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (px, &color) in pixels.iter_mut().zip(self.0.iter()) {
+            *px = color.into();
+        }
+        PixelFrame(pixels)
+    }
+
     /// Returns the `FrameLine` as a slice of bytes.
     pub fn as_bytes(&self) -> [u8; 128] {
         self.0
@@ -90,6 +139,16 @@ impl fmt::Debug for FrameLine {
     }
 }
 
+impl fmt::Display for FrameLine {
+    /// Formats the 128 bytes of the `FrameLine` as a continuous, lowercase, hex string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.as_bytes().iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
 impl PartialEq for FrameLine {
     fn eq(&self, other: &FrameLine) -> bool {
         self.0
@@ -129,6 +188,13 @@ impl PartialEq for PixelFrame {
 }
 
 impl PixelFrame {
+    /// The number of columns in a `PixelFrame`.
+    pub const WIDTH: usize = 8;
+    /// The number of rows in a `PixelFrame`.
+    pub const HEIGHT: usize = 8;
+    /// The number of pixels in a `PixelFrame`.
+    pub const PIXEL_COUNT: usize = 64;
+
     pub const BLACK: PixelFrame = PixelFrame([PixelColor::BLACK; 64]);
     pub const RED: PixelFrame = PixelFrame([PixelColor::RED; 64]);
     pub const BLUE: PixelFrame = PixelFrame([PixelColor::BLUE; 64]);
@@ -207,6 +273,16 @@ impl PixelFrame {
         columns
     }
 
+    /// Returns a `Vec` of 8 rows, from top to bottom, each with 8 `PixelColor`s.
+    pub fn rows_vec(&self) -> Vec<Vec<PixelColor>> {
+        self.as_rows().iter().map(|row| row.to_vec()).collect()
+    }
+
+    /// Returns a `Vec` of 8 columns, from left to right, each with 8 `PixelColor`s.
+    pub fn columns_vec(&self) -> Vec<Vec<PixelColor>> {
+        self.as_columns().iter().map(|col| col.to_vec()).collect()
+    }
+
     /// Create a new `PixelFrame` from a `[[PixelColor; 8]; 8]`, of 8 rows with 8 `PixelColor`s.
     pub fn from_rows(rows: &[[PixelColor; 8]; 8]) -> Self {
         let mut pixels = [PixelColor::default(); 64];
@@ -218,6 +294,18 @@ impl PixelFrame {
         PixelFrame(pixels)
     }
 
+    /// Create a new `PixelFrame` from a `Vec<PixelColor>`, in row-major order.
+    ///
+    /// Returns `ScreenError::InvalidPixelCount` unless the vector has exactly 64 pixels.
+    pub fn from_vec(pixels: Vec<PixelColor>) -> Result<Self, ScreenError> {
+        if pixels.len() != 64 {
+            return Err(ScreenError::InvalidPixelCount { len: pixels.len() });
+        }
+        let mut frame = PixelFrame::BLACK;
+        frame.0.copy_from_slice(&pixels);
+        Ok(frame)
+    }
+
     /// Create a new `PixelFrame` from a `[[PixelColor; 8]; 8]`, of 8 columns with 8 `PixelColor`s.
     pub fn from_columns(columns: &[[PixelColor; 8]; 8]) -> Self {
         let mut pixels = [PixelColor::default(); 64];
@@ -228,239 +316,2010 @@ impl PixelFrame {
         }
         PixelFrame(pixels)
     }
-}
 
-impl<'a> From<&'a [PixelColor; 64]> for PixelFrame {
-    fn from(array: &'a [PixelColor; 64]) -> Self {
-        PixelFrame::new(array)
+    /// Returns a `u64` monochrome bitmap of the frame, where a bit is set if the pixel is not
+    /// black.
+    ///
+    /// Pixels are mapped in row-major order, so that bit `n` (counting from the least
+    /// significant bit) corresponds to the pixel at index `n`.
+    pub fn to_mono_bits(&self) -> u64 {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, &px)| px != PixelColor::BLACK)
+            .fold(0u64, |bits, (idx, _)| bits | 1 << idx)
     }
-}
 
-impl From<[PixelColor; 64]> for PixelFrame {
-    fn from(array: [PixelColor; 64]) -> Self {
-        PixelFrame(array)
+    /// Create a new `PixelFrame` from a `u64` monochrome bitmap, painting set bits with the `on`
+    /// color, and unset bits with the `off` color.
+    ///
+    /// Pixels are mapped in row-major order, so that bit `n` (counting from the least
+    /// significant bit) corresponds to the pixel at index `n`.
+    pub fn from_mono_bits(bits: u64, on: PixelColor, off: PixelColor) -> Self {
+        let mut pixels = [off; 64];
+        for (idx, px) in pixels.iter_mut().enumerate() {
+            if bits & 1 << idx > 0 {
+                *px = on;
+            }
+        }
+        PixelFrame(pixels)
     }
-}
 
-impl Into<[PixelColor; 64]> for PixelFrame {
-    fn into(self) -> [PixelColor; 64] {
-        self.0
+    /// Create a new `PixelFrame` with a 1-pixel `color` outline painted on the black pixels
+    /// that surround the non-black pixels of the frame, including diagonals.
+    pub fn outline(&self, color: PixelColor) -> Self {
+        let mut outlined = *self;
+        let outline = outline_bits(self.to_mono_bits());
+        for (idx, px) in outlined.0.iter_mut().enumerate() {
+            if outline & 1 << idx > 0 {
+                *px = color;
+            }
+        }
+        outlined
     }
-}
 
-impl Index<usize> for PixelFrame {
-    type Output = PixelColor;
+    /// Create a new `PixelFrame` with an underscore cursor, drawn in `color` on the bottom row,
+    /// over a black background.
+    pub fn underscore_cursor(color: PixelColor) -> Self {
+        let mut pixels = [PixelColor::BLACK; 64];
+        for px in pixels[56..].iter_mut() {
+            *px = color;
+        }
+        PixelFrame(pixels)
+    }
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.0[index]
+    /// Returns the monochrome bitmap of the frame as 8 bytes, one per row, with the same
+    /// layout used by the `font8x8` crate. A bit is set if the pixel is not black.
+    pub fn to_font_bytes(&self) -> [u8; 8] {
+        self.to_mono_bits().to_le_bytes()
     }
-}
 
-impl IndexMut<usize> for PixelFrame {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.0[index]
+    /// Returns the logical RGB565 value of every pixel, as a `u16`, independent of the
+    /// byte order used by the framebuffer.
+    pub fn to_rgb565_u16(&self) -> [u16; 64] {
+        let mut colors = [0u16; 64];
+        for (color, px) in colors.iter_mut().zip(self.0.iter()) {
+            *color = Rgb565::from(px).into();
+        }
+        colors
     }
-}
 
-/// Offset for `PixelFrame` displacement in a given direction
-#[cfg(any(feature = "offset", feature = "clip"))]
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub enum Offset {
-    Left(u8),
-    Right(u8),
-    Bottom(u8),
-    Top(u8),
-}
+    /// Create a new `PixelFrame` from the logical RGB565 value of every pixel, as a `u16`,
+    /// independent of the byte order used by the framebuffer.
+    pub fn from_rgb565_u16(colors: [u16; 64]) -> Self {
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (px, &color) in pixels.iter_mut().zip(colors.iter()) {
+            *px = Rgb565::from(color).into();
+        }
+        PixelFrame(pixels)
+    }
 
-#[cfg(any(feature = "offset", feature = "clip"))]
-impl Offset {
-    /// Offset by `offset` pixels to the left of the LED Matrix.
+    /// Create a new `PixelFrame` from ASCII art, with 8 lines of 8 characters. Spaces and
+    /// dots are black pixels, and every other character is painted with its color in the
+    /// `legend`. Leading and trailing newlines are ignored.
     ///
-    /// # Panics
-    /// If `offset` is greater than 8.
-    pub fn left(offset: u8) -> Self {
-        assert!(offset < 9);
-        Offset::Left(offset)
+    /// Returns `ScreenError::InvalidAsciiArt` if the art is not `8x8`, or if a character is
+    /// missing from the `legend`.
+    pub fn from_ascii(
+        art: &str,
+        legend: &HashMap<char, PixelColor>,
+    ) -> Result<PixelFrame, ScreenError> {
+        let lines: Vec<&str> = art
+            .trim_matches(|c| c == '\n' || c == '\r')
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .collect();
+        if lines.len() != 8 {
+            return Err(ScreenError::InvalidAsciiArt);
+        }
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (row, line) in lines.iter().enumerate() {
+            if line.chars().count() != 8 {
+                return Err(ScreenError::InvalidAsciiArt);
+            }
+            for (col, c) in line.chars().enumerate() {
+                pixels[row * 8 + col] = match c {
+                    ' ' | '.' => PixelColor::BLACK,
+                    _ => *legend.get(&c).ok_or(ScreenError::InvalidAsciiArt)?,
+                };
+            }
+        }
+        Ok(PixelFrame(pixels))
     }
 
-    /// Offset by `offset` pixels to the right of the LED Matrix.
+    /// Returns an SVG image of the frame, with a square `<rect>` of `cell_px` pixels for
+    /// every LED.
+    pub fn to_svg(&self, cell_px: usize) -> String {
+        let side = cell_px * 8;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{0}\" viewBox=\"0 0 {0} {0}\">\n",
+            side
+        );
+        for (idx, color) in self.0.iter().enumerate() {
+            writeln!(
+                &mut svg,
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{size}\" height=\"{size}\" fill=\"#{r:02x}{g:02x}{b:02x}\"/>",
+                x = idx % 8 * cell_px,
+                y = idx / 8 * cell_px,
+                size = cell_px,
+                r = color.red,
+                g = color.green,
+                b = color.blue
+            )
+            .unwrap();
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Returns a compact text preview of the frame, with two lines of four Unicode Braille
+    /// characters. Each character shows a `2x4` block of pixels, with a dot for every pixel
+    /// that is not black.
+    pub fn to_braille(&self) -> String {
+        // Braille dot bits, indexed by row and column inside the `2x4` block.
+        const DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+        let mut lines = Vec::with_capacity(2);
+        for block_row in 0..2 {
+            let mut line = String::with_capacity(12);
+            for block_col in 0..4 {
+                let mut bits = 0;
+                for (row, dots) in DOTS.iter().enumerate() {
+                    for (col, dot) in dots.iter().enumerate() {
+                        let idx = (block_row * 4 + row) * 8 + block_col * 2 + col;
+                        if self.0[idx] != PixelColor::BLACK {
+                            bits |= dot;
+                        }
+                    }
+                }
+                line.push(std::char::from_u32(0x2800 + bits).unwrap());
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+
+    /// Create a new `PixelFrame` with the top-left `4x4` quadrant mirrored horizontally and
+    /// vertically into the other three quadrants.
+    pub fn kaleidoscope(&self) -> Self {
+        let mut frame = *self;
+        for row in 0..8 {
+            for col in 0..8 {
+                let (src_row, src_col) = (row.min(7 - row), col.min(7 - col));
+                frame[row * 8 + col] = self[src_row * 8 + src_col];
+            }
+        }
+        frame
+    }
+
+    /// Create a new `PixelFrame` with a checkerboard pattern, starting with `first` at the
+    /// top-left corner, and alternating with `second`.
+    pub fn checkerboard(first: PixelColor, second: PixelColor) -> Self {
+        PixelFrame::tile(&[first, second, second, first], 2, 2)
+    }
+
+    /// Create a new `PixelFrame` by repeating a `width * height` pattern, in row-major
+    /// order, from the top-left corner. The pattern is cut at the right, and bottom, edges
+    /// when its dimensions do not divide `8`.
     ///
     /// # Panics
-    /// If `offset` is greater than 8.
-    pub fn right(offset: u8) -> Self {
-        assert!(offset < 9);
-        Offset::Right(offset)
+    /// If `width` or `height` are `0`, or if the number of pixels is not `width * height`.
+    pub fn tile(pattern: &[PixelColor], width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0);
+        assert_eq!(pattern.len(), width * height);
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (idx, px) in pixels.iter_mut().enumerate() {
+            let (row, col) = (idx / 8, idx % 8);
+            *px = pattern[(row % height) * width + col % width];
+        }
+        PixelFrame(pixels)
     }
 
-    /// Offset by `offset` pixels to the bottom of the LED Matrix.
+    /// Returns `count` copies of this frame, to hold it on screen for `count` frames of an
+    /// animation.
+    pub fn hold(&self, count: usize) -> Vec<PixelFrame> {
+        vec![*self; count]
+    }
+
+    /// Returns `Some` color if every pixel of the frame has that same color, or `None`
+    /// otherwise.
+    pub fn is_uniform(&self) -> Option<PixelColor> {
+        let first = self.0[0];
+        if self.0.iter().all(|&color| color == first) {
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if every pixel of the frame is black.
+    pub fn is_blank(&self) -> bool {
+        self.is_uniform() == Some(PixelColor::BLACK)
+    }
+
+    /// Create a new `PixelFrame` showing a number, from `0` to `99`, as two `3x5` digits
+    /// side-by-side, each on its own half of the screen. Values below `10` have a leading
+    /// zero.
     ///
     /// # Panics
-    /// If `offset` is greater than 8.
-    pub fn bottom(offset: u8) -> Self {
-        assert!(offset < 9);
-        Offset::Bottom(offset)
+    /// If `value` is greater than 99.
+    pub fn two_digits(value: u8, color: PixelColor, bg: PixelColor) -> Self {
+        assert!(value < 100);
+        let mut pixels = [bg; 64];
+        for (left, digit) in [(0, value / 10), (4, value % 10)].iter() {
+            for (row, bits) in DIGITS_3X5[usize::from(*digit)].iter().enumerate() {
+                for col in 0..3 {
+                    if bits & (0b100 >> col) != 0 {
+                        pixels[(row + 1) * 8 + left + col] = color;
+                    }
+                }
+            }
+        }
+        PixelFrame(pixels)
     }
 
-    /// Offset by `offset` pixels to the top of the LED Matrix.
+    /// Create a new `PixelFrame` from 64 scalar values, in row-major order, as a heatmap.
+    /// Each value is normalized from the `min..=max` range, and mapped through the evenly
+    /// spaced stops of the `gradient`, blending between the two nearest stops.
+    ///
+    /// Values out of range are clamped, and every value maps to the first stop when `max`
+    /// is not greater than `min`.
     ///
     /// # Panics
-    /// If `offset` is greater than 8.
-    pub fn top(offset: u8) -> Self {
-        assert!(offset < 9);
-        Offset::Top(offset)
+    /// If the `gradient` is empty.
+    pub fn from_scalar_field(
+        values: &[f32; 64],
+        min: f32,
+        max: f32,
+        gradient: &[PixelColor],
+    ) -> Self {
+        assert!(!gradient.is_empty());
+        let last = gradient.len() - 1;
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (px, &value) in pixels.iter_mut().zip(values.iter()) {
+            let t = if max > min {
+                ((value - min) / (max - min)).max(0.0).min(1.0)
+            } else {
+                0.0
+            };
+            let position = t * last as f32;
+            let stop = (position.floor() as usize).min(last);
+            *px = match gradient.get(stop + 1) {
+                Some(next) => gradient[stop].lerp(next, position - stop as f32),
+                None => gradient[stop],
+            };
+        }
+        PixelFrame(pixels)
     }
-}
 
-#[cfg(any(feature = "offset", feature = "clip"))]
-fn clip_pixel_frames_offset_left(first: PixelFrame, second: PixelFrame, offset: u8) -> PixelFrame {
-    assert!(offset < 9);
-    match offset as usize {
-        0 => first,
-        8 => second,
-        n => {
-            let mut orig = first.as_columns();
-            let second = second.as_columns();
-            {
-                orig.rotate_left(n);
-                let (_, right) = orig.split_at_mut(8 - n);
-                right.copy_from_slice(&second[..n]);
+    /// Create a new two-color `PixelFrame` from an `8x8` PBM image, in the plain (`P1`) or
+    /// raw (`P4`) format. Set bits, which PBM draws in black, are `on` pixels, and unset
+    /// bits are `off` pixels.
+    ///
+    /// Returns `ScreenError::InvalidPbm` if the image is malformed, or is not `8x8`.
+    pub fn from_pbm(bytes: &[u8], on: PixelColor, off: PixelColor) -> Result<Self, ScreenError> {
+        let mut pos = 2;
+        let raw = match bytes.get(..2) {
+            Some(b"P1") => false,
+            Some(b"P4") => true,
+            _ => return Err(ScreenError::InvalidPbm),
+        };
+        let width = pbm_header_value(bytes, &mut pos)?;
+        let height = pbm_header_value(bytes, &mut pos)?;
+        if (width, height) != (8, 8) {
+            return Err(ScreenError::InvalidPbm);
+        }
+        let mut pixels = [off; 64];
+        if raw {
+            // A single whitespace byte separates the header from the rows of packed bits.
+            let rows = bytes.get(pos + 1..pos + 9).ok_or(ScreenError::InvalidPbm)?;
+            for (idx, px) in pixels.iter_mut().enumerate() {
+                if rows[idx / 8] & (0x80 >> (idx % 8)) != 0 {
+                    *px = on;
+                }
+            }
+        } else {
+            let mut bits = bytes[pos..]
+                .iter()
+                .filter(|b| !b.is_ascii_whitespace())
+                .map(|&b| match b {
+                    b'0' => Ok(false),
+                    b'1' => Ok(true),
+                    _ => Err(ScreenError::InvalidPbm),
+                });
+            for px in pixels.iter_mut() {
+                if bits.next().ok_or(ScreenError::InvalidPbm)?? {
+                    *px = on;
+                }
             }
-            PixelFrame::from_columns(&orig)
         }
+        Ok(PixelFrame(pixels))
     }
-}
 
-#[cfg(any(feature = "offset", feature = "clip"))]
-fn clip_pixel_frames_offset_right(first: PixelFrame, second: PixelFrame, offset: u8) -> PixelFrame {
-    match offset as usize {
-        0 => first,
-        8 => second,
-        n => {
-            let mut orig = first.as_columns();
-            let second = second.as_columns();
-            {
-                orig.rotate_right(n);
-                let (left, _) = orig.split_at_mut(n);
-                left.copy_from_slice(&second[8 - n..]);
-            }
-            PixelFrame::from_columns(&orig)
+    /// Create a new `PixelFrame` of random-looking static, with each pixel picked from the
+    /// `palette` by a pseudo-random generator. The same `seed` always produces the same
+    /// frame.
+    ///
+    /// # Panics
+    /// If the `palette` is empty.
+    pub fn noise(seed: u64, palette: &[PixelColor]) -> Self {
+        assert!(!palette.is_empty());
+        let mut rng = XorShift::new(seed);
+        let mut pixels = [PixelColor::BLACK; 64];
+        for px in pixels.iter_mut() {
+            *px = palette[(rng.next_u64() % palette.len() as u64) as usize];
         }
+        PixelFrame(pixels)
     }
-}
 
-#[cfg(any(feature = "offset", feature = "clip"))]
-fn clip_pixel_frames_offset_top(first: PixelFrame, second: PixelFrame, offset: u8) -> PixelFrame {
-    match offset as usize {
-        0 => first,
-        8 => second,
-        n => {
-            let mut orig = first.as_rows();
-            let second = second.as_rows();
-            {
-                orig.rotate_left(n);
-                let (_, right) = orig.split_at_mut(8 - n);
-                right.copy_from_slice(&second[..n]);
-            }
-            PixelFrame::from_rows(&orig)
+    /// Create a new `PixelFrame` of smooth value noise, sampled over the grid at `scale`
+    /// lattice cells per pixel, and at time `t`. Each pixel blends from `color_lo` to
+    /// `color_hi` with the noise value. Advancing `t` in small steps makes the noise flow
+    /// like clouds.
+    pub fn value_noise_frame(
+        t: f32,
+        scale: f32,
+        color_lo: PixelColor,
+        color_hi: PixelColor,
+    ) -> Self {
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (idx, px) in pixels.iter_mut().enumerate() {
+            let (x, y) = ((idx % 8) as f32 * scale, (idx / 8) as f32 * scale);
+            *px = color_lo.lerp(&color_hi, value_noise(x, y, t));
         }
+        PixelFrame(pixels)
     }
-}
 
-#[cfg(any(feature = "offset", feature = "clip"))]
-fn clip_pixel_frames_offset_bottom(
-    first: PixelFrame,
-    second: PixelFrame,
-    offset: u8,
-) -> PixelFrame {
-    match offset as usize {
-        0 => first,
-        8 => second,
-        n => {
-            let mut orig = first.as_rows();
-            let second = second.as_rows();
-            {
-                orig.rotate_right(n);
-                let (left, _) = orig.split_at_mut(n);
-                left.copy_from_slice(&second[8 - n..]);
+    /// Returns the color of the pixel at the given `Point`.
+    pub fn pixel(&self, point: Point) -> PixelColor {
+        self[point]
+    }
+
+    /// Paint the pixel at the given `Point` with `color`.
+    pub fn set_pixel(&mut self, point: Point, color: PixelColor) {
+        self[point] = color;
+    }
+
+    /// Paint each pixel in `updates` with its color, in order. Positions are either `Point`s,
+    /// or `(col, row)` pairs.
+    ///
+    /// # Panics
+    /// If any position is outside of the screen.
+    pub fn set_pixels<I, P>(&mut self, updates: I)
+    where
+        I: IntoIterator<Item = (P, PixelColor)>,
+        P: Into<Point>,
+    {
+        for (point, color) in updates {
+            self[point.into()] = color;
+        }
+    }
+
+    /// Paint every pixel inside the `Rect` with `color`.
+    pub fn fill_rect(&mut self, rect: Rect, color: PixelColor) {
+        for point in rect.points() {
+            self[point] = color;
+        }
+    }
+
+    /// Paint every pixel within `radius` of the `(col, row)` center, whose angle from the
+    /// center lies between `start_deg` and `end_deg`. Angles are measured counter-clockwise
+    /// from the right, so that `90.0` points up; the sector wraps around `0.0` when `end_deg`
+    /// is less than `start_deg`.
+    pub fn draw_sector(
+        &mut self,
+        center: (usize, usize),
+        radius: usize,
+        start_deg: f32,
+        end_deg: f32,
+        color: PixelColor,
+    ) {
+        let normalize = |deg: f32| ((deg % 360.0) + 360.0) % 360.0;
+        let full = (end_deg - start_deg).abs() >= 360.0;
+        let (start, end) = (normalize(start_deg), normalize(end_deg));
+        for (idx, px) in self.0.iter_mut().enumerate() {
+            let dx = (idx % 8) as f32 - center.0 as f32;
+            let dy = center.1 as f32 - (idx / 8) as f32;
+            if dx.hypot(dy) > radius as f32 {
+                continue;
+            }
+            let angle = normalize(dy.atan2(dx).to_degrees());
+            let inside = if full || (dx == 0.0 && dy == 0.0) {
+                true
+            } else if start <= end {
+                angle >= start && angle <= end
+            } else {
+                angle >= start || angle <= end
+            };
+            if inside {
+                *px = color;
             }
-            PixelFrame::from_rows(&orig)
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Returns a drawing context over this frame, whose coordinates count from the given
+    /// `Origin`.
+    pub fn with_origin(&mut self, origin: Origin) -> FrameCanvas<'_> {
+        FrameCanvas {
+            frame: self,
+            origin,
+        }
+    }
 
-    const RED: PixelColor = PixelColor::RED;
-    const ONE: PixelColor = PixelColor::WHITE;
-    const TWO: PixelColor = PixelColor::BLUE;
-    const PIXEL_FRAME: &[PixelColor; 64] = &[
-        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
-        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
-        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
-        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
-        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
-        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
-        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
-        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
-    ];
-    fn test_rows() -> [[PixelColor; 8]; 8] {
-        [[RED, ONE, RED, TWO, RED, ONE, RED, TWO]; 8]
+    /// Create a new `PixelFrame` that keeps the pixels inside the `Rect`, in place, and
+    /// paints every other pixel black.
+    pub fn crop(&self, rect: Rect) -> PixelFrame {
+        let mut cropped = PixelFrame::BLACK;
+        for point in rect.points() {
+            cropped[point] = self[point];
+        }
+        cropped
     }
-    fn test_columns() -> [[PixelColor; 8]; 8] {
-        [
-            [RED; 8], [ONE; 8], [RED; 8], [TWO; 8], [RED; 8], [ONE; 8], [RED; 8], [TWO; 8],
-        ]
+
+    /// Copy the pixels inside the `Rect` of the `source` frame into this frame, with the
+    /// top-left corner of the region placed at `to`. Pixels that fall outside of the screen
+    /// are clipped.
+    pub fn copy_region(&mut self, source: &PixelFrame, rect: Rect, to: Point) {
+        let width = rect.width().min(8 - to.col);
+        let height = rect.height().min(8 - to.row);
+        let from = rect.top_left();
+        for dy in 0..height {
+            for dx in 0..width {
+                self[Point::new(to.col + dx, to.row + dy)] =
+                    source[Point::new(from.col + dx, from.row + dy)];
+            }
+        }
     }
 
-    #[test]
-    fn frame_line_is_created_from_slice_of_bytes() {
-        let color: [u8; 128] = [0xE0; 128];
-        let frame_line = FrameLine::from_slice(&color);
-        frame_line
-            .as_bytes()
+    /// Returns `true` if both frames are displayed the same, after quantizing them to RGB565.
+    pub fn approx_eq(&self, other: &PixelFrame) -> bool {
+        self.0
             .iter()
-            .zip(color.iter())
-            .for_each(|(a, b)| {
-                assert_eq!(a, b);
-            });
+            .zip(other.0.iter())
+            .all(|(px, other_px)| px.approx_eq(other_px))
     }
 
-    #[cfg(not(feature = "big-endian"))]
-    #[test]
-    fn frame_line_is_created_from_slice_of_pixel_color() {
-        let blue = PixelColor::from_rgb565_bytes([0x1F, 0x00]);
-        let frame_line = FrameLine::from_pixels(&[blue; 64]);
-        frame_line.as_bytes().chunks(2).for_each(|chunk| {
-            assert_eq!([chunk[0], chunk[1]], [0x1F, 0x00]);
+    /// Returns the mean `PixelColor` of all 64 pixels in the frame.
+    pub fn average_color(&self) -> PixelColor {
+        let (red, green, blue) = self.0.iter().fold((0u32, 0u32, 0u32), |(r, g, b), px| {
+            (
+                r + u32::from(px.red),
+                g + u32::from(px.green),
+                b + u32::from(px.blue),
+            )
         });
+        PixelColor::new((red / 64) as u8, (green / 64) as u8, (blue / 64) as u8)
     }
 
-    #[cfg(feature = "big-endian")]
-    #[test]
-    fn frame_line_is_created_from_slice_of_pixel_color() {
-        let blue = PixelColor::from_rgb565_bytes([0x00, 0x1F]);
-        let frame_line = FrameLine::from_pixels(&[blue; 64]);
-        frame_line.as_bytes().chunks(2).for_each(|chunk| {
-            assert_eq!([chunk[0], chunk[1]], [0x00, 0x1F]);
-        });
+    /// Create a new `PixelFrame` with every pixel dimmed by `scale`, as `PixelColor::dim`
+    /// does.
+    pub fn dim(&self, scale: f32) -> Self {
+        let mut pixels = self.0;
+        for px in pixels.iter_mut() {
+            *px = px.dim(scale);
+        }
+        PixelFrame(pixels)
     }
 
-    #[test]
-    fn pixel_frame_is_created_from_a_slice_of_pixel_color() {
-        let color_frame = [PixelColor::YELLOW; 64];
-        let pixel_frame = PixelFrame::new(&color_frame);
-        pixel_frame
+    /// Create a new `PixelFrame` with its colors stretched to use the full dynamic range, so
+    /// that the darkest pixel becomes black, and the brightest becomes full brightness.
+    ///
+    /// The range is measured with the luma of each pixel, and every channel is stretched by
+    /// the same amount. A frame with a single luma is returned unchanged.
+    pub fn auto_levels(&self) -> Self {
+        let luma = |px: &PixelColor| {
+            0.2126 * f32::from(px.red) + 0.7152 * f32::from(px.green) + 0.0722 * f32::from(px.blue)
+        };
+        let (min, max) = self
             .0
             .iter()
-            .zip(color_frame.iter())
-            .for_each(|(a, b)| {
-                assert_eq!(a, b);
-            });
+            .map(luma)
+            .fold((255f32, 0f32), |(min, max), l| (min.min(l), max.max(l)));
+        if max - min < 1.0 {
+            return *self;
+        }
+        let stretch = |channel: u8| {
+            let stretched = (f32::from(channel) - min) * 255.0 / (max - min);
+            stretched.round().max(0.0).min(255.0) as u8
+        };
+        let mut leveled = *self;
+        for px in leveled.0.iter_mut() {
+            *px = PixelColor::new(stretch(px.red), stretch(px.green), stretch(px.blue));
+        }
+        leveled
     }
 
-    #[test]
+    /// Create a new `PixelFrame` with each color channel quantized to `levels` evenly-spaced
+    /// values, from `0` to `255`.
+    ///
+    /// # Panics
+    /// If `levels` is less than 2.
+    pub fn posterize(&self, levels: u8) -> Self {
+        assert!(levels >= 2);
+        let steps = u32::from(levels - 1);
+        let quantize = |channel: u8| {
+            let step = (u32::from(channel) * steps + 127) / 255;
+            (step * 255 / steps) as u8
+        };
+        let mut posterized = *self;
+        for px in posterized.0.iter_mut() {
+            *px = PixelColor::new(quantize(px.red), quantize(px.green), quantize(px.blue));
+        }
+        posterized
+    }
+
+    /// Create a new `PixelFrame` with the hue of every pixel shifted by `degrees`, wrapping
+    /// around `360.0`.
+    pub fn rotate_hue(&self, degrees: f32) -> Self {
+        let mut rotated = *self;
+        for px in rotated.0.iter_mut() {
+            let (hue, saturation, value) = px.to_hsv();
+            *px = PixelColor::from_hsv(hue + degrees, saturation, value);
+        }
+        rotated
+    }
+
+    /// Create a new `PixelFrame` by layering `other` over this frame, combining the colors of
+    /// each pixel with the given `BlendMode`.
+    pub fn composite(&self, other: &PixelFrame, mode: BlendMode) -> Self {
+        let mut composited = *self;
+        for (px, top) in composited.0.iter_mut().zip(other.0.iter()) {
+            *px = mode.blend(*px, *top);
+        }
+        composited
+    }
+
+    /// Returns the number of pixels painted with each distinct `PixelColor` in the frame.
+    pub fn color_histogram(&self) -> HashMap<PixelColor, usize> {
+        let mut histogram = HashMap::new();
+        for px in self.0.iter() {
+            *histogram.entry(*px).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Returns the most frequent `PixelColor` in the frame. Ties are resolved in favor of
+    /// the color that appears first, in row-major order.
+    pub fn dominant_color(&self) -> PixelColor {
+        let mut counts: Vec<(PixelColor, usize)> = Vec::new();
+        for px in self.0.iter() {
+            match counts.iter_mut().find(|(color, _)| color == px) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((*px, 1)),
+            }
+        }
+        counts
+            .iter()
+            .fold((PixelColor::BLACK, 0), |max, &(color, count)| {
+                if count > max.1 {
+                    (color, count)
+                } else {
+                    max
+                }
+            })
+            .0
+    }
+}
+
+// Parse the next decimal value of a PBM header, starting at `pos`, and skipping whitespace
+// and comments. Leaves `pos` at the byte that follows the value.
+fn pbm_header_value(bytes: &[u8], pos: &mut usize) -> Result<usize, ScreenError> {
+    while let Some(&b) = bytes.get(*pos) {
+        match b {
+            b'#' => {
+                while let Some(&b) = bytes.get(*pos) {
+                    if b == b'\n' {
+                        break;
+                    }
+                    *pos += 1;
+                }
+            }
+            b if b.is_ascii_whitespace() => *pos += 1,
+            _ => break,
+        }
+    }
+    let start = *pos;
+    while let Some(b'0'..=b'9') = bytes.get(*pos) {
+        *pos += 1;
+    }
+    std::str::from_utf8(&bytes[start..*pos])
+        .ok()
+        .and_then(|digits| digits.parse().ok())
+        .ok_or(ScreenError::InvalidPbm)
+}
+
+// A xorshift pseudo-random generator, for reproducible effects without dependencies.
+pub(crate) struct XorShift(u64);
+
+impl XorShift {
+    pub(crate) fn new(seed: u64) -> Self {
+        // xorshift gets stuck on a zero state, so it is replaced by an arbitrary non-zero one.
+        if seed == 0 {
+            XorShift(0x9E37_79B9_7F4A_7C15)
+        } else {
+            XorShift(seed)
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+// Smooth noise in the `0.0..=1.0` range, interpolating pseudo-random values at the corners
+// of the integer lattice cell around `(x, y, z)`.
+fn value_noise(x: f32, y: f32, z: f32) -> f32 {
+    fn lattice(x: i32, y: i32, z: i32) -> f32 {
+        let mut hash = (x as u32)
+            .wrapping_mul(0x8DA6_B343)
+            .wrapping_add((y as u32).wrapping_mul(0xD816_3841))
+            .wrapping_add((z as u32).wrapping_mul(0xCB1A_B31F));
+        hash ^= hash >> 15;
+        hash = hash.wrapping_mul(0x2C1B_3C6D);
+        hash ^= hash >> 12;
+        (hash & 0xFFFF) as f32 / 65535.0
+    }
+    fn smooth(t: f32) -> f32 {
+        t * t * (3.0 - 2.0 * t)
+    }
+    fn lerp(a: f32, b: f32, t: f32) -> f32 {
+        a + (b - a) * t
+    }
+    let (x0, y0, z0) = (x.floor(), y.floor(), z.floor());
+    let (tx, ty, tz) = (smooth(x - x0), smooth(y - y0), smooth(z - z0));
+    let (x0, y0, z0) = (x0 as i32, y0 as i32, z0 as i32);
+    let plane = |z| {
+        let top = lerp(lattice(x0, y0, z), lattice(x0 + 1, y0, z), tx);
+        let bottom = lerp(lattice(x0, y0 + 1, z), lattice(x0 + 1, y0 + 1, z), tx);
+        lerp(top, bottom, ty)
+    };
+    lerp(plane(z0), plane(z0 + 1), tz)
+}
+
+// Compact `3x5` glyphs for the decimal digits, one row per byte, with the left column in
+// the `0b100` bit.
+const DIGITS_3X5: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b001, 0b001, 0b001],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+// Returns the unset bits of a monochrome bitmap, in the layout of `PixelFrame::to_mono_bits`,
+// that are next to a set bit, including diagonals.
+pub(crate) fn outline_bits(bits: u64) -> u64 {
+    const FIRST_COLUMN: u64 = 0x0101_0101_0101_0101;
+    const LAST_COLUMN: u64 = 0x8080_8080_8080_8080;
+    let wide = bits | (bits << 1 & !FIRST_COLUMN) | (bits >> 1 & !LAST_COLUMN);
+    let grown = wide | wide << 8 | wide >> 8;
+    grown & !bits
+}
+
+impl<'a> From<&'a [PixelColor; 64]> for PixelFrame {
+    fn from(array: &'a [PixelColor; 64]) -> Self {
+        PixelFrame::new(array)
+    }
+}
+
+impl From<[PixelColor; 64]> for PixelFrame {
+    fn from(array: [PixelColor; 64]) -> Self {
+        PixelFrame(array)
+    }
+}
+
+impl Into<[PixelColor; 64]> for PixelFrame {
+    fn into(self) -> [PixelColor; 64] {
+        self.0
+    }
+}
+
+impl From<PixelFrame> for Vec<PixelColor> {
+    fn from(frame: PixelFrame) -> Self {
+        frame.0.to_vec()
+    }
+}
+
+impl Index<usize> for PixelFrame {
+    type Output = PixelColor;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl IndexMut<usize> for PixelFrame {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl Index<Point> for PixelFrame {
+    type Output = PixelColor;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        &self.0[point.to_index()]
+    }
+}
+
+impl IndexMut<Point> for PixelFrame {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        &mut self.0[point.to_index()]
+    }
+}
+
+/// A layer of pixels with an alpha channel, to composite text, sprites, and effects, before
+/// they are flattened into a `PixelFrame` for display.
+#[derive(Copy, Clone)]
+pub struct RgbaFrame([Rgba; 64]);
+
+impl fmt::Debug for RgbaFrame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows = self.0.chunks(8).fold(String::new(), |mut s, row| {
+            writeln!(&mut s, "{:?}", row).unwrap();
+            s
+        });
+        write!(f, "RgbaFrame:\n{}", rows)
+    }
+}
+
+impl Default for RgbaFrame {
+    fn default() -> Self {
+        RgbaFrame::TRANSPARENT
+    }
+}
+
+impl PartialEq for RgbaFrame {
+    fn eq(&self, other: &RgbaFrame) -> bool {
+        self.0.iter().zip(other.0.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl RgbaFrame {
+    pub const TRANSPARENT: RgbaFrame = RgbaFrame([Rgba::TRANSPARENT; 64]);
+
+    /// Create a new `RgbaFrame` from an array of `Rgba` colors, in row-major order.
+    pub fn new(pixels: &[Rgba; 64]) -> Self {
+        RgbaFrame(*pixels)
+    }
+
+    /// Create a new `RgbaFrame` by compositing every pixel of this layer over the pixel of
+    /// the `bottom` layer, with the source-over operator.
+    pub fn over(&self, bottom: &RgbaFrame) -> Self {
+        let mut pixels = self.0;
+        for (px, under) in pixels.iter_mut().zip(bottom.0.iter()) {
+            *px = px.over(*under);
+        }
+        RgbaFrame(pixels)
+    }
+
+    /// Create a new `PixelFrame` by flattening this layer onto an opaque `bg` color.
+    pub fn flatten(&self, bg: PixelColor) -> PixelFrame {
+        let mut pixels = [bg; 64];
+        for (px, color) in pixels.iter_mut().zip(self.0.iter()) {
+            *px = color.to_pixel_color(bg);
+        }
+        PixelFrame(pixels)
+    }
+}
+
+impl From<PixelFrame> for RgbaFrame {
+    /// Create a fully opaque layer.
+    fn from(frame: PixelFrame) -> Self {
+        let mut pixels = [Rgba::TRANSPARENT; 64];
+        for (px, &color) in pixels.iter_mut().zip(frame.0.iter()) {
+            *px = Rgba::from(color);
+        }
+        RgbaFrame(pixels)
+    }
+}
+
+impl Index<usize> for RgbaFrame {
+    type Output = Rgba;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl IndexMut<usize> for RgbaFrame {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+/// How the colors of two layered `PixelFrame`s are combined by `PixelFrame::composite`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BlendMode {
+    /// The top layer replaces the bottom layer.
+    Normal,
+    /// The channels are added, saturating at `255`.
+    Add,
+    /// The channels are multiplied, darkening the result.
+    Multiply,
+    /// The inverted channels are multiplied, and inverted back, lightening the result.
+    Screen,
+    /// The largest value of each channel.
+    Max,
+    /// The smallest value of each channel.
+    Min,
+}
+
+impl BlendMode {
+    // Combine a pixel of the bottom layer with a pixel of the top layer.
+    fn blend(self, bottom: PixelColor, top: PixelColor) -> PixelColor {
+        let per_channel = |blend: fn(u8, u8) -> u8| {
+            PixelColor::new(
+                blend(bottom.red, top.red),
+                blend(bottom.green, top.green),
+                blend(bottom.blue, top.blue),
+            )
+        };
+        match self {
+            BlendMode::Normal => top,
+            BlendMode::Add => per_channel(u8::saturating_add),
+            BlendMode::Multiply => bottom.multiply(&top),
+            BlendMode::Screen => bottom.screen(&top),
+            BlendMode::Max => per_channel(std::cmp::max),
+            BlendMode::Min => per_channel(std::cmp::min),
+        }
+    }
+}
+
+/// The corner of the screen that drawing coordinates count from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// `(0, 0)` is the top-left pixel, and rows grow downwards.
+    TopLeft,
+    /// `(0, 0)` is the bottom-left pixel, and rows grow upwards.
+    BottomLeft,
+}
+
+// Deriving `Default` for an enum needs the `#[default]` attribute, which is not available on
+// Rust 1.32, so the impl is written by hand.
+#[allow(clippy::derivable_impls)]
+impl Default for Origin {
+    fn default() -> Self {
+        Origin::TopLeft
+    }
+}
+
+/// A drawing context over a `PixelFrame`, with coordinates counted from its `Origin`.
+#[derive(Debug)]
+pub struct FrameCanvas<'a> {
+    frame: &'a mut PixelFrame,
+    origin: Origin,
+}
+
+impl<'a> FrameCanvas<'a> {
+    /// The `Origin` that coordinates count from.
+    pub fn origin(&self) -> Origin {
+        self.origin
+    }
+
+    /// Returns the screen `Point` at `(x, y)`, or `None` if it is outside of the screen.
+    pub fn point(&self, x: usize, y: usize) -> Option<Point> {
+        if x > 7 || y > 7 {
+            return None;
+        }
+        match self.origin {
+            Origin::TopLeft => Point::checked(x as u8, y as u8),
+            Origin::BottomLeft => Point::checked(x as u8, 7 - y as u8),
+        }
+    }
+
+    /// Paint the pixel at `(x, y)` with `color`. Positions outside of the screen are ignored.
+    pub fn plot(&mut self, x: usize, y: usize, color: PixelColor) {
+        if let Some(point) = self.point(x, y) {
+            self.frame[point] = color;
+        }
+    }
+
+    /// Paint a sector around the `(x, y)` center, as `PixelFrame::draw_sector` does.
+    pub fn draw_sector(
+        &mut self,
+        center: (usize, usize),
+        radius: usize,
+        start_deg: f32,
+        end_deg: f32,
+        color: PixelColor,
+    ) {
+        if let Some(point) = self.point(center.0, center.1) {
+            let center = (usize::from(point.col), usize::from(point.row));
+            self.frame
+                .draw_sector(center, radius, start_deg, end_deg, color);
+        }
+    }
+}
+
+/// The position of a pixel on the screen, by column and row, counting from the top-left
+/// corner.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Point {
+    col: u8,
+    row: u8,
+}
+
+impl Point {
+    /// Create a new `Point` at the given column and row.
+    ///
+    /// # Panics
+    /// If `col` or `row` are greater than 7.
+    pub fn new(col: u8, row: u8) -> Self {
+        assert!(col < 8 && row < 8);
+        Point { col, row }
+    }
+
+    /// Create a new `Point` at the given column and row, or `None` if either is greater
+    /// than 7.
+    pub fn checked(col: u8, row: u8) -> Option<Self> {
+        if col < 8 && row < 8 {
+            Some(Point { col, row })
+        } else {
+            None
+        }
+    }
+
+    /// Create a new `Point` from the index of a pixel in row-major order, or `None` if the
+    /// index is greater than 63.
+    pub fn from_index(index: usize) -> Option<Self> {
+        if index < 64 {
+            Some(Point {
+                col: (index % 8) as u8,
+                row: (index / 8) as u8,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The column of the pixel, from `0` to `7`.
+    pub fn col(self) -> u8 {
+        self.col
+    }
+
+    /// The row of the pixel, from `0` to `7`.
+    pub fn row(self) -> u8 {
+        self.row
+    }
+
+    /// Returns the index of the pixel in row-major order.
+    pub fn to_index(self) -> usize {
+        usize::from(self.row) * 8 + usize::from(self.col)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    /// Create a new `Point` from a `(col, row)` pair.
+    ///
+    /// # Panics
+    /// If `col` or `row` are greater than 7.
+    fn from((col, row): (usize, usize)) -> Self {
+        assert!(col < 8 && row < 8);
+        Point {
+            col: col as u8,
+            row: row as u8,
+        }
+    }
+}
+
+/// A rectangular region of the screen, clamped to the `8x8` grid.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rect {
+    top_left: Point,
+    width: u8,
+    height: u8,
+}
+
+impl Rect {
+    /// Create a new `Rect` from its top-left corner, and its size. The size is clamped so
+    /// that the region fits in the screen.
+    pub fn new(top_left: Point, width: u8, height: u8) -> Self {
+        Rect {
+            top_left,
+            width: width.min(8 - top_left.col),
+            height: height.min(8 - top_left.row),
+        }
+    }
+
+    /// The top-left corner of the region.
+    pub fn top_left(&self) -> Point {
+        self.top_left
+    }
+
+    /// The width of the region, in pixels.
+    pub fn width(&self) -> u8 {
+        self.width
+    }
+
+    /// The height of the region, in pixels.
+    pub fn height(&self) -> u8 {
+        self.height
+    }
+
+    /// Returns `true` if the `Point` is inside the region.
+    pub fn contains(&self, point: Point) -> bool {
+        point.col >= self.top_left.col
+            && point.col - self.top_left.col < self.width
+            && point.row >= self.top_left.row
+            && point.row - self.top_left.row < self.height
+    }
+
+    /// Iterate over every `Point` in the region, in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Rect {
+            top_left,
+            width,
+            height,
+        } = *self;
+        (top_left.row..top_left.row + height).flat_map(move |row| {
+            (top_left.col..top_left.col + width).map(move |col| Point { col, row })
+        })
+    }
+}
+
+/// Offset for `PixelFrame` displacement in a given direction
+#[cfg(any(feature = "offset", feature = "clip"))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Offset {
+    Left(u8),
+    Right(u8),
+    Bottom(u8),
+    Top(u8),
+}
+
+#[cfg(any(feature = "offset", feature = "clip"))]
+impl Offset {
+    /// Offset by `offset` pixels to the left of the LED Matrix.
+    ///
+    /// # Panics
+    /// If `offset` is greater than 8.
+    pub fn left(offset: u8) -> Self {
+        assert!(offset < 9);
+        Offset::Left(offset)
+    }
+
+    /// Offset by `offset` pixels to the right of the LED Matrix.
+    ///
+    /// # Panics
+    /// If `offset` is greater than 8.
+    pub fn right(offset: u8) -> Self {
+        assert!(offset < 9);
+        Offset::Right(offset)
+    }
+
+    /// Offset by `offset` pixels to the bottom of the LED Matrix.
+    ///
+    /// # Panics
+    /// If `offset` is greater than 8.
+    pub fn bottom(offset: u8) -> Self {
+        assert!(offset < 9);
+        Offset::Bottom(offset)
+    }
+
+    /// Offset by `offset` pixels to the top of the LED Matrix.
+    ///
+    /// # Panics
+    /// If `offset` is greater than 8.
+    pub fn top(offset: u8) -> Self {
+        assert!(offset < 9);
+        Offset::Top(offset)
+    }
+}
+
+#[cfg(feature = "offset")]
+fn clip_pixel_frames_offset_left(first: PixelFrame, second: PixelFrame, offset: u8) -> PixelFrame {
+    assert!(offset < 9);
+    match offset as usize {
+        0 => first,
+        8 => second,
+        n => PixelFrame::from_columns(&slide_lines_forward(
+            &first.as_columns(),
+            &second.as_columns(),
+            n,
+        )),
+    }
+}
+
+#[cfg(feature = "offset")]
+fn clip_pixel_frames_offset_right(first: PixelFrame, second: PixelFrame, offset: u8) -> PixelFrame {
+    match offset as usize {
+        0 => first,
+        8 => second,
+        n => PixelFrame::from_columns(&slide_lines_backward(
+            &first.as_columns(),
+            &second.as_columns(),
+            n,
+        )),
+    }
+}
+
+#[cfg(feature = "offset")]
+fn clip_pixel_frames_offset_top(first: PixelFrame, second: PixelFrame, offset: u8) -> PixelFrame {
+    match offset as usize {
+        0 => first,
+        8 => second,
+        n => PixelFrame::from_rows(&slide_lines_forward(&first.as_rows(), &second.as_rows(), n)),
+    }
+}
+
+#[cfg(feature = "offset")]
+fn clip_pixel_frames_offset_bottom(
+    first: PixelFrame,
+    second: PixelFrame,
+    offset: u8,
+) -> PixelFrame {
+    match offset as usize {
+        0 => first,
+        8 => second,
+        n => PixelFrame::from_rows(&slide_lines_backward(
+            &first.as_rows(),
+            &second.as_rows(),
+            n,
+        )),
+    }
+}
+
+// Move the `first` lines `n` positions towards the start, and fill the vacated lines at the
+// end with the leading lines of `second`.
+#[cfg(any(feature = "offset", feature = "clip"))]
+fn slide_lines_forward(
+    first: &[[PixelColor; 8]; 8],
+    second: &[[PixelColor; 8]; 8],
+    n: usize,
+) -> [[PixelColor; 8]; 8] {
+    let mut lines = *first;
+    lines.rotate_left(n);
+    lines[8 - n..].copy_from_slice(&second[..n]);
+    lines
+}
+
+// Move the `first` lines `n` positions towards the end, and fill the vacated lines at the
+// start with the trailing lines of `second`.
+#[cfg(any(feature = "offset", feature = "clip"))]
+fn slide_lines_backward(
+    first: &[[PixelColor; 8]; 8],
+    second: &[[PixelColor; 8]; 8],
+    n: usize,
+) -> [[PixelColor; 8]; 8] {
+    let mut lines = *first;
+    lines.rotate_right(n);
+    lines[..n].copy_from_slice(&second[8 - n..]);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: PixelColor = PixelColor::RED;
+    const ONE: PixelColor = PixelColor::WHITE;
+    const TWO: PixelColor = PixelColor::BLUE;
+    const PIXEL_FRAME: &[PixelColor; 64] = &[
+        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
+        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
+        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
+        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
+        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
+        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
+        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
+        RED, ONE, RED, TWO, RED, ONE, RED, TWO, //
+    ];
+    fn test_rows() -> [[PixelColor; 8]; 8] {
+        [[RED, ONE, RED, TWO, RED, ONE, RED, TWO]; 8]
+    }
+    fn test_columns() -> [[PixelColor; 8]; 8] {
+        [
+            [RED; 8], [ONE; 8], [RED; 8], [TWO; 8], [RED; 8], [ONE; 8], [RED; 8], [TWO; 8],
+        ]
+    }
+
+    #[test]
+    fn frame_line_is_converted_back_to_pixel_frame() {
+        let mut pixels = [PixelColor::new(0xF8, 0x04, 0x18); 64];
+        pixels[63] = PixelColor::new(0x08, 0xFC, 0xF8);
+        let frame = PixelFrame::new(&pixels);
+        assert_eq!(frame.frame_line().to_pixel_frame(), frame);
+    }
+
+    #[test]
+    fn pixel_frame_maps_to_rgb565_u16_values() {
+        assert_eq!(PixelFrame::WHITE.to_rgb565_u16(), [0xFFFF; 64]);
+        assert_eq!(PixelFrame::BLACK.to_rgb565_u16(), [0x0000; 64]);
+        assert_eq!(PixelFrame::RED.to_rgb565_u16(), [0xF800; 64]);
+    }
+
+    #[test]
+    fn pixel_frame_is_created_from_rgb565_u16_values() {
+        let mut colors = [0xF800; 64];
+        colors[63] = 0x001F;
+        let frame = PixelFrame::from_rgb565_u16(colors);
+        assert_eq!(frame[0], PixelColor::new(0xF8, 0, 0));
+        assert_eq!(frame[63], PixelColor::new(0, 0, 0xF8));
+        assert_eq!(frame.to_rgb565_u16(), colors);
+    }
+
+    #[test]
+    fn point_is_round_tripped_through_its_index() {
+        for index in 0..64 {
+            let point = Point::from_index(index).unwrap();
+            assert_eq!(point.to_index(), index);
+            assert_eq!(Point::new(point.col, point.row), point);
+        }
+        assert_eq!(Point::from_index(64), None);
+    }
+
+    #[test]
+    fn point_axes_are_column_and_row() {
+        assert_eq!(Point::new(1, 2).to_index(), 17);
+        assert_eq!(Point::from_index(15), Some(Point { col: 7, row: 1 }));
+    }
+
+    #[test]
+    fn point_is_checked_against_screen_bounds() {
+        assert_eq!(Point::checked(7, 7), Some(Point { col: 7, row: 7 }));
+        assert_eq!(Point::checked(8, 0), None);
+        assert_eq!(Point::checked(0, 8), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn point_out_of_bounds_panics() {
+        let _ = Point::new(0, 8);
+    }
+
+    #[test]
+    fn pixel_frame_pixels_are_set_by_point() {
+        let mut frame = PixelFrame::BLACK;
+        frame.set_pixel(Point::new(2, 1), PixelColor::RED);
+        assert_eq!(frame[10], PixelColor::RED);
+        assert_eq!(frame.pixel(Point::new(2, 1)), PixelColor::RED);
+        assert_eq!(frame[Point::new(1, 2)], PixelColor::BLACK);
+    }
+
+    #[test]
+    fn pixel_frame_sets_a_batch_of_pixels() {
+        let mut frame = PixelFrame::BLACK;
+        frame.set_pixels(vec![
+            ((0, 0), PixelColor::RED),
+            ((7, 1), PixelColor::GREEN),
+            ((3, 7), PixelColor::BLUE),
+        ]);
+        for index in 0..64 {
+            let expected = match index {
+                0 => PixelColor::RED,
+                15 => PixelColor::GREEN,
+                59 => PixelColor::BLUE,
+                _ => PixelColor::BLACK,
+            };
+            assert_eq!(frame[index], expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn pixel_frame_batch_with_position_out_of_bounds_panics() {
+        let mut frame = PixelFrame::BLACK;
+        frame.set_pixels(vec![((8, 0), PixelColor::RED)]);
+    }
+
+    #[test]
+    fn pixel_frame_is_approx_equal_to_its_rgb565_round_trip() {
+        let frame = PixelFrame::new(PIXEL_FRAME);
+        let round_trip = frame.frame_line().to_pixel_frame();
+        assert_ne!(round_trip, frame);
+        assert!(round_trip.approx_eq(&frame));
+        assert!(!round_trip.approx_eq(&PixelFrame::BLACK));
+    }
+
+    #[test]
+    fn pixel_frame_counts_each_color_in_histogram() {
+        let mut frame = PixelFrame::RED;
+        frame.fill_rect(Rect::new(Point::new(0, 4), 8, 4), PixelColor::BLUE);
+        let histogram = frame.color_histogram();
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram[&PixelColor::RED], 32);
+        assert_eq!(histogram[&PixelColor::BLUE], 32);
+    }
+
+    #[test]
+    fn pixel_frame_posterized_to_2_levels_snaps_channels_to_extremes() {
+        let mut frame = PixelFrame::BLACK;
+        for (idx, px) in frame.0.iter_mut().enumerate() {
+            let value = (idx * 4) as u8;
+            *px = PixelColor::new(value, 255 - value, value / 2);
+        }
+        for px in frame.posterize(2).0.iter() {
+            for &channel in &[px.red, px.green, px.blue] {
+                assert!(channel == 0 || channel == 255);
+            }
+        }
+        let gray = PixelFrame::new(&[PixelColor::new(100, 127, 128); 64]);
+        assert_eq!(gray.posterize(2)[0], PixelColor::new(0, 0, 255));
+    }
+
+    #[test]
+    fn pixel_frame_posterized_to_3_levels_uses_evenly_spaced_values() {
+        let frame = PixelFrame::new(&[PixelColor::new(60, 140, 200); 64]);
+        assert_eq!(frame.posterize(3)[0], PixelColor::new(0, 127, 255));
+    }
+
+    #[test]
+    #[should_panic]
+    fn pixel_frame_posterized_to_less_than_2_levels_panics() {
+        let _ = PixelFrame::BLACK.posterize(1);
+    }
+
+    #[test]
+    fn pixel_frame_hue_is_rotated_with_wrapping() {
+        assert_eq!(PixelFrame::RED.rotate_hue(120.0), PixelFrame::GREEN);
+        assert_eq!(PixelFrame::RED.rotate_hue(-120.0), PixelFrame::BLUE);
+        assert_eq!(PixelFrame::BLUE.rotate_hue(480.0), PixelFrame::RED);
+        assert_eq!(PixelFrame::WHITE.rotate_hue(90.0), PixelFrame::WHITE);
+    }
+
+    #[test]
+    fn pixel_frame_composite_multiply_keeps_colors_over_white_and_black_over_black() {
+        let frame = PixelFrame::new(PIXEL_FRAME);
+        assert_eq!(
+            PixelFrame::WHITE.composite(&frame, BlendMode::Multiply),
+            frame
+        );
+        assert_eq!(
+            frame.composite(&PixelFrame::WHITE, BlendMode::Multiply),
+            frame
+        );
+        assert_eq!(
+            frame.composite(&PixelFrame::BLACK, BlendMode::Multiply),
+            PixelFrame::BLACK
+        );
+    }
+
+    #[test]
+    fn pixel_frame_composite_screen_is_inverse_multiply() {
+        let frame = PixelFrame::new(PIXEL_FRAME);
+        assert_eq!(
+            frame.composite(&PixelFrame::BLACK, BlendMode::Screen),
+            frame
+        );
+        assert_eq!(
+            frame.composite(&PixelFrame::WHITE, BlendMode::Screen),
+            PixelFrame::WHITE
+        );
+        let gray = PixelFrame::new(&[PixelColor::new(128, 128, 128); 64]);
+        assert_eq!(
+            gray.composite(&gray, BlendMode::Screen)[0],
+            PixelColor::new(192, 192, 192)
+        );
+        assert_eq!(
+            gray.composite(&gray, BlendMode::Multiply)[0],
+            PixelColor::new(64, 64, 64)
+        );
+    }
+
+    #[test]
+    fn pixel_frame_composite_other_modes() {
+        let low = PixelFrame::new(&[PixelColor::new(200, 10, 100); 64]);
+        let high = PixelFrame::new(&[PixelColor::new(100, 20, 100); 64]);
+        assert_eq!(low.composite(&high, BlendMode::Normal), high);
+        assert_eq!(
+            low.composite(&high, BlendMode::Add)[0],
+            PixelColor::new(255, 30, 200)
+        );
+        assert_eq!(
+            low.composite(&high, BlendMode::Max)[0],
+            PixelColor::new(200, 20, 100)
+        );
+        assert_eq!(
+            low.composite(&high, BlendMode::Min)[0],
+            PixelColor::new(100, 10, 100)
+        );
+    }
+
+    #[test]
+    fn pixel_frame_auto_levels_stretches_to_full_range() {
+        let mut frame = PixelFrame::BLACK;
+        for (idx, px) in frame.0.iter_mut().enumerate() {
+            let value = 0x20 + (idx * 0x60 / 63) as u8;
+            *px = PixelColor::new(value, value, value);
+        }
+        let leveled = frame.auto_levels();
+        assert_eq!(leveled[0], PixelColor::BLACK);
+        assert_eq!(leveled[63], PixelColor::WHITE);
+        let mid = leveled[32].red;
+        assert!(mid > 0x70 && mid < 0x90);
+    }
+
+    #[test]
+    fn pixel_frame_auto_levels_of_a_single_color_is_unchanged() {
+        let frame = PixelFrame::new(&[PixelColor::new(0x20, 0x40, 0x60); 64]);
+        assert_eq!(frame.auto_levels(), frame);
+    }
+
+    #[test]
+    fn pixel_frame_is_parsed_from_ascii_art() {
+        let smiley = "
+..YYYY..
+.Y....Y.
+Y.B..B.Y
+Y......Y
+Y.R..R.Y
+Y..RR..Y
+.Y....Y.
+..YYYY..
+";
+        let mut legend = HashMap::new();
+        legend.insert('Y', PixelColor::YELLOW);
+        legend.insert('B', PixelColor::BLUE);
+        legend.insert('R', PixelColor::RED);
+        let frame = PixelFrame::from_ascii(smiley, &legend).unwrap();
+        assert_eq!(frame[Point::new(0, 0)], PixelColor::BLACK);
+        assert_eq!(frame[Point::new(2, 0)], PixelColor::YELLOW);
+        assert_eq!(frame[Point::new(2, 2)], PixelColor::BLUE);
+        assert_eq!(frame[Point::new(4, 5)], PixelColor::RED);
+        assert_eq!(frame.color_histogram()[&PixelColor::YELLOW], 20);
+    }
+
+    #[test]
+    fn pixel_frame_from_invalid_ascii_art_fails() {
+        let mut legend = HashMap::new();
+        legend.insert('#', PixelColor::WHITE);
+        let row = "########\n";
+        assert!(PixelFrame::from_ascii(&row.repeat(8), &legend).is_ok());
+        assert!(PixelFrame::from_ascii(&row.repeat(7), &legend).is_err());
+        assert!(PixelFrame::from_ascii(&"#######\n".repeat(8), &legend).is_err());
+        assert!(PixelFrame::from_ascii(&"#######?\n".repeat(8), &legend).is_err());
+    }
+
+    #[test]
+    fn pixel_frame_is_rendered_as_svg() {
+        let mut frame = PixelFrame::BLUE;
+        frame[9] = PixelColor::RED;
+        let svg = frame.to_svg(10);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains(" width=\"80\" height=\"80\""));
+        assert_eq!(svg.matches("<rect").count(), 64);
+        assert!(
+            svg.contains("<rect x=\"10\" y=\"10\" width=\"10\" height=\"10\" fill=\"#ff0000\"/>")
+        );
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn pixel_frame_rows_and_columns_as_vecs() {
+        let frame = PixelFrame::new(PIXEL_FRAME);
+        let rows = frame.rows_vec();
+        assert_eq!(rows.len(), 8);
+        for (row, expected) in rows.iter().zip(frame.as_rows().iter()) {
+            assert_eq!(&row[..], &expected[..]);
+        }
+        let columns = frame.columns_vec();
+        assert_eq!(columns.len(), 8);
+        for (col, expected) in columns.iter().zip(frame.as_columns().iter()) {
+            assert_eq!(&col[..], &expected[..]);
+        }
+    }
+
+    #[test]
+    fn kaleidoscope_mirrors_the_top_left_quadrant() {
+        let mut frame = PixelFrame::BLACK;
+        frame.set_pixel(Point::new(1, 2), PixelColor::RED);
+        frame.set_pixel(Point::new(6, 6), PixelColor::BLUE);
+        let mirrored = frame.kaleidoscope();
+        let lit: Vec<Point> = (0..64)
+            .filter(|&idx| mirrored[idx] != PixelColor::BLACK)
+            .map(|idx| Point::from_index(idx).unwrap())
+            .collect();
+        assert_eq!(
+            lit,
+            vec![
+                Point::new(1, 2),
+                Point::new(6, 2),
+                Point::new(1, 5),
+                Point::new(6, 5)
+            ]
+        );
+        assert!(lit.iter().all(|&point| mirrored[point] == PixelColor::RED));
+    }
+
+    #[test]
+    fn tiling_a_2x2_checker_motif_matches_the_checkerboard() {
+        let (red, blue) = (PixelColor::RED, PixelColor::BLUE);
+        let frame = PixelFrame::tile(&[red, blue, blue, red], 2, 2);
+        assert_eq!(frame, PixelFrame::checkerboard(red, blue));
+        let rows = frame.as_rows();
+        assert_eq!(rows[0], [red, blue, red, blue, red, blue, red, blue]);
+        assert_eq!(rows[1], [blue, red, blue, red, blue, red, blue, red]);
+    }
+
+    #[test]
+    fn tiling_wraps_patterns_that_do_not_divide_the_screen() {
+        let (red, blue) = (PixelColor::RED, PixelColor::BLUE);
+        let frame = PixelFrame::tile(&[red, blue, blue], 3, 1);
+        for row in frame.as_rows().iter() {
+            assert_eq!(row, &[red, blue, blue, red, blue, blue, red, blue]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn tiling_needs_width_times_height_pixels() {
+        let _ = PixelFrame::tile(&[PixelColor::RED; 3], 2, 2);
+    }
+
+    #[test]
+    fn pixel_frame_is_held_for_count_frames() {
+        let frames = PixelFrame::RED.hold(5);
+        assert_eq!(frames, vec![PixelFrame::RED; 5]);
+        assert!(PixelFrame::RED.hold(0).is_empty());
+    }
+
+    #[test]
+    fn pixel_frame_of_a_single_color_is_uniform() {
+        assert_eq!(PixelFrame::RED.is_uniform(), Some(PixelColor::RED));
+        assert!(!PixelFrame::RED.is_blank());
+        assert!(PixelFrame::BLACK.is_blank());
+    }
+
+    #[test]
+    fn pixel_frame_of_mixed_colors_is_not_uniform() {
+        let mut frame = PixelFrame::BLACK;
+        frame[63] = PixelColor::RED;
+        assert_eq!(frame.is_uniform(), None);
+        assert!(!frame.is_blank());
+    }
+
+    #[test]
+    fn two_digits_are_drawn_side_by_side() {
+        let mut legend = HashMap::new();
+        legend.insert('#', PixelColor::WHITE);
+        let expected = PixelFrame::from_ascii(
+            "
+........
+#.#.###.
+#.#...#.
+###.###.
+..#.#...
+..#.###.
+........
+........",
+            &legend,
+        )
+        .unwrap();
+        assert_eq!(
+            PixelFrame::two_digits(42, PixelColor::WHITE, PixelColor::BLACK),
+            expected
+        );
+    }
+
+    #[test]
+    fn two_digits_pad_single_digits_with_a_zero() {
+        let frame = PixelFrame::two_digits(7, PixelColor::WHITE, PixelColor::BLUE);
+        let rows = frame.as_rows();
+        assert_eq!(rows[0], [PixelColor::BLUE; 8]);
+        assert_eq!(
+            rows[2][..3],
+            [PixelColor::WHITE, PixelColor::BLUE, PixelColor::WHITE]
+        );
+        assert_eq!(
+            rows[2][4..7],
+            [PixelColor::BLUE, PixelColor::BLUE, PixelColor::WHITE]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn two_digits_panic_above_99() {
+        let _ = PixelFrame::two_digits(100, PixelColor::WHITE, PixelColor::BLACK);
+    }
+
+    #[test]
+    fn scalar_field_is_mapped_through_a_two_stop_gradient() {
+        let mut values = [15.0; 64];
+        values[0] = 10.0;
+        values[1] = 20.0;
+        values[2] = -5.0;
+        values[3] = 99.0;
+        let gradient = [PixelColor::BLUE, PixelColor::RED];
+        let frame = PixelFrame::from_scalar_field(&values, 10.0, 20.0, &gradient);
+        assert_eq!(frame[0], PixelColor::BLUE);
+        assert_eq!(frame[1], PixelColor::RED);
+        assert_eq!(frame[2], PixelColor::BLUE);
+        assert_eq!(frame[3], PixelColor::RED);
+        assert_eq!(frame[4], PixelColor::new(128, 0, 128));
+    }
+
+    #[test]
+    fn scalar_field_is_mapped_through_every_gradient_stop() {
+        let mut values = [0.0; 64];
+        values[1] = 0.5;
+        values[2] = 0.75;
+        let gradient = [PixelColor::BLUE, PixelColor::GREEN, PixelColor::RED];
+        let frame = PixelFrame::from_scalar_field(&values, 0.0, 1.0, &gradient);
+        assert_eq!(frame[0], PixelColor::BLUE);
+        assert_eq!(frame[1], PixelColor::GREEN);
+        assert_eq!(frame[2], PixelColor::new(128, 128, 0));
+        let flat = PixelFrame::from_scalar_field(&values, 1.0, 1.0, &gradient);
+        assert_eq!(flat, PixelFrame::BLUE);
+    }
+
+    #[test]
+    fn pixel_frame_is_parsed_from_a_plain_pbm_checkerboard() {
+        let pbm = b"P1
+# checkerboard
+8 8
+1 0 1 0 1 0 1 0
+0 1 0 1 0 1 0 1
+1 0 1 0 1 0 1 0
+0 1 0 1 0 1 0 1
+1 0 1 0 1 0 1 0
+0 1 0 1 0 1 0 1
+1 0 1 0 1 0 1 0
+0 1 0 1 0 1 0 1
+";
+        let frame = PixelFrame::from_pbm(pbm, PixelColor::WHITE, PixelColor::BLACK).unwrap();
+        assert_eq!(
+            frame,
+            PixelFrame::checkerboard(PixelColor::WHITE, PixelColor::BLACK)
+        );
+    }
+
+    #[test]
+    fn pixel_frame_is_parsed_from_a_raw_pbm() {
+        let mut pbm = b"P4\n8 8\n".to_vec();
+        pbm.extend_from_slice(&[0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55, 0xAA, 0x55]);
+        let frame = PixelFrame::from_pbm(&pbm, PixelColor::RED, PixelColor::BLUE).unwrap();
+        assert_eq!(
+            frame,
+            PixelFrame::checkerboard(PixelColor::RED, PixelColor::BLUE)
+        );
+    }
+
+    #[test]
+    fn pixel_frame_from_invalid_pbm_fails() {
+        let (on, off) = (PixelColor::WHITE, PixelColor::BLACK);
+        let ones = "1".repeat(64);
+        let pbm = |header: &str| format!("{}\n{}", header, ones).into_bytes();
+        assert!(PixelFrame::from_pbm(&pbm("P1 8 8"), on, off).is_ok());
+        assert!(PixelFrame::from_pbm(&pbm("P1 8 7"), on, off).is_err());
+        assert!(PixelFrame::from_pbm(&pbm("P2 8 8"), on, off).is_err());
+        assert!(PixelFrame::from_pbm(b"P1 8 8 0101", on, off).is_err());
+        assert!(PixelFrame::from_pbm(b"P4 8 8 \xFF", on, off).is_err());
+    }
+
+    #[test]
+    fn noise_is_reproduced_from_the_same_seed() {
+        let palette = [PixelColor::BLACK, PixelColor::WHITE, PixelColor::BLUE];
+        let noise = PixelFrame::noise(7, &palette);
+        assert_eq!(noise, PixelFrame::noise(7, &palette));
+        assert_ne!(noise, PixelFrame::noise(8, &palette));
+        assert!((0..64).all(|idx| palette.contains(&noise[idx])));
+        assert_eq!(noise.color_histogram().len(), 3);
+    }
+
+    #[test]
+    fn value_noise_flows_smoothly_between_time_steps() {
+        let (lo, hi) = (PixelColor::new(0, 0, 40), PixelColor::new(200, 0, 240));
+        let now = PixelFrame::value_noise_frame(0.5, 0.3, lo, hi);
+        let next = PixelFrame::value_noise_frame(0.55, 0.3, lo, hi);
+        assert_ne!(now, next);
+        for idx in 0..64 {
+            let change = (i16::from(now[idx].red) - i16::from(next[idx].red)).abs();
+            assert!(change <= 30, "pixel {} changed by {}", idx, change);
+            let color = now[idx];
+            assert_eq!(color.green, 0);
+            assert_eq!(i16::from(color.blue) - i16::from(color.red), 40);
+        }
+        assert!(now.color_histogram().len() > 8);
+    }
+
+    #[test]
+    fn sector_lights_a_slice_of_the_disc() {
+        let lit = |start, end| {
+            let mut frame = PixelFrame::BLACK;
+            frame.draw_sector((3, 3), 3, start, end, PixelColor::WHITE);
+            frame.color_histogram()[&PixelColor::WHITE]
+        };
+        let disc = lit(0.0, 360.0);
+        let quarter = lit(0.0, 90.0);
+        assert!(
+            quarter * 4 >= disc && quarter * 2 <= disc,
+            "{} of {}",
+            quarter,
+            disc
+        );
+        let mut frame = PixelFrame::BLACK;
+        frame.draw_sector((3, 3), 3, 270.0, 90.0, PixelColor::WHITE);
+        assert_eq!(frame[Point::new(6, 3)], PixelColor::WHITE);
+        assert_eq!(frame[Point::new(0, 3)], PixelColor::BLACK);
+        assert_eq!(lit(270.0, 90.0), lit(90.0, 270.0));
+    }
+
+    #[test]
+    fn bottom_left_origin_plots_with_y_up() {
+        let mut frame = PixelFrame::BLACK;
+        frame
+            .with_origin(Origin::BottomLeft)
+            .plot(0, 0, PixelColor::RED);
+        assert_eq!(frame[Point::new(0, 7)], PixelColor::RED);
+        frame
+            .with_origin(Origin::default())
+            .plot(0, 0, PixelColor::BLUE);
+        assert_eq!(frame[Point::new(0, 0)], PixelColor::BLUE);
+        frame
+            .with_origin(Origin::BottomLeft)
+            .plot(8, 0, PixelColor::GREEN);
+        assert_eq!(frame.color_histogram().len(), 3);
+    }
+
+    #[test]
+    fn pixel_frame_is_previewed_as_braille() {
+        assert_eq!(
+            PixelFrame::WHITE.to_braille(),
+            "\u{28FF}\u{28FF}\u{28FF}\u{28FF}\n\u{28FF}\u{28FF}\u{28FF}\u{28FF}"
+        );
+        assert_eq!(
+            PixelFrame::BLACK.to_braille(),
+            "\u{2800}\u{2800}\u{2800}\u{2800}\n\u{2800}\u{2800}\u{2800}\u{2800}"
+        );
+        let mut frame = PixelFrame::BLACK;
+        frame[Point::new(0, 0)] = PixelColor::RED;
+        frame[Point::new(3, 3)] = PixelColor::RED;
+        frame[Point::new(7, 4)] = PixelColor::RED;
+        let preview: Vec<char> = frame.to_braille().chars().collect();
+        assert_eq!(preview[0], '\u{2801}');
+        assert_eq!(preview[1], '\u{2880}');
+        assert_eq!(preview[8], '\u{2808}');
+    }
+
+    #[test]
+    fn half_transparent_red_layer_over_blue_flattens_to_purple() {
+        let red = RgbaFrame::new(&[Rgba::new(PixelColor::RED, 128); 64]);
+        let blue = RgbaFrame::from(PixelFrame::BLUE);
+        let purple = PixelFrame::new(&[PixelColor::new(128, 0, 127); 64]);
+        assert_eq!(red.over(&blue).flatten(PixelColor::BLACK), purple);
+        assert_eq!(red.flatten(PixelColor::BLUE), purple);
+        let mut layer = RgbaFrame::default();
+        layer[0] = Rgba::from(PixelColor::GREEN);
+        let flat = layer.over(&blue).flatten(PixelColor::BLACK);
+        assert_eq!(flat[0], PixelColor::GREEN);
+        assert_eq!(flat[1], PixelColor::BLUE);
+    }
+
+    #[test]
+    fn point_exposes_its_column_and_row() {
+        let point = Point::new(3, 6);
+        assert_eq!((point.col(), point.row()), (3, 6));
+        assert_eq!(Point::from_index(point.to_index()), Some(point));
+    }
+
+    #[test]
+    fn rect_is_clamped_to_the_screen() {
+        let rect = Rect::new(Point::new(5, 6), 10, 10);
+        assert_eq!((rect.width(), rect.height()), (3, 2));
+        let rect = Rect::new(Point::new(0, 0), 8, 8);
+        assert_eq!((rect.width(), rect.height()), (8, 8));
+    }
+
+    #[test]
+    fn rect_points_are_iterated_in_row_major_order() {
+        let rect = Rect::new(Point::new(6, 6), 5, 5);
+        let points: Vec<Point> = rect.points().collect();
+        assert_eq!(
+            points,
+            vec![
+                Point::new(6, 6),
+                Point::new(7, 6),
+                Point::new(6, 7),
+                Point::new(7, 7),
+            ]
+        );
+        assert!(rect.contains(Point::new(7, 7)));
+        assert!(!rect.contains(Point::new(5, 7)));
+    }
+
+    #[test]
+    fn pixel_frame_fills_and_crops_rects() {
+        let rect = Rect::new(Point::new(1, 1), 2, 3);
+        let mut frame = PixelFrame::BLUE;
+        frame.fill_rect(rect, PixelColor::RED);
+        for index in 0..64 {
+            let point = Point::from_index(index).unwrap();
+            let expected = if rect.contains(point) {
+                PixelColor::RED
+            } else {
+                PixelColor::BLUE
+            };
+            assert_eq!(frame[point], expected);
+        }
+        let cropped = frame.crop(rect);
+        assert_eq!(cropped.to_mono_bits().count_ones(), 6);
+        assert_eq!(cropped[Point::new(2, 3)], PixelColor::RED);
+    }
+
+    #[test]
+    fn pixel_frame_copies_regions_with_clipping() {
+        let mut source = PixelFrame::BLACK;
+        source.fill_rect(Rect::new(Point::new(0, 0), 2, 2), PixelColor::RED);
+        let mut frame = PixelFrame::BLACK;
+        frame.copy_region(&source, Rect::new(Point::new(0, 0), 2, 2), Point::new(7, 3));
+        assert_eq!(frame[Point::new(7, 3)], PixelColor::RED);
+        assert_eq!(frame[Point::new(7, 4)], PixelColor::RED);
+        assert_eq!(frame.to_mono_bits().count_ones(), 2);
+    }
+
+    #[test]
+    fn pixel_frame_copies_regions_clipped_at_the_right_and_bottom_edges() {
+        let mut source = PixelFrame::BLACK;
+        for (idx, px) in source.0.iter_mut().enumerate() {
+            *px = PixelColor::new(idx as u8 + 1, 0, 0);
+        }
+        let mut frame = PixelFrame::BLACK;
+        frame.copy_region(&source, Rect::new(Point::new(0, 0), 3, 2), Point::new(6, 0));
+        assert_eq!(frame[Point::new(6, 0)], source[Point::new(0, 0)]);
+        assert_eq!(frame[Point::new(7, 0)], source[Point::new(1, 0)]);
+        assert_eq!(frame[Point::new(6, 1)], source[Point::new(0, 1)]);
+        assert_eq!(frame[Point::new(7, 1)], source[Point::new(1, 1)]);
+        assert_eq!(frame.color_histogram()[&PixelColor::BLACK], 60);
+
+        let mut frame = PixelFrame::BLACK;
+        frame.copy_region(&source, Rect::new(Point::new(1, 1), 2, 3), Point::new(0, 6));
+        assert_eq!(frame[Point::new(0, 6)], source[Point::new(1, 1)]);
+        assert_eq!(frame[Point::new(1, 6)], source[Point::new(2, 1)]);
+        assert_eq!(frame[Point::new(0, 7)], source[Point::new(1, 2)]);
+        assert_eq!(frame[Point::new(1, 7)], source[Point::new(2, 2)]);
+        assert_eq!(frame.color_histogram()[&PixelColor::BLACK], 60);
+    }
+
+    #[test]
+    fn outline_bits_surround_set_bits_without_wrapping() {
+        // A single pixel at the end of the first row.
+        assert_eq!(outline_bits(1 << 7), 1 << 6 | 1 << 14 | 1 << 15);
+        // A single pixel at the center.
+        let center = outline_bits(1 << 27);
+        assert_eq!(center.count_ones(), 8);
+        assert_eq!(center, 0b111 << 18 | 0b101 << 26 | 0b111 << 34);
+    }
+
+    #[test]
+    fn pixel_frame_outline_paints_around_lit_pixels() {
+        let mut frame = PixelFrame::BLACK;
+        frame[Point::new(0, 0)] = PixelColor::RED;
+        let outlined = frame.outline(PixelColor::BLUE);
+        assert_eq!(outlined[Point::new(0, 0)], PixelColor::RED);
+        assert_eq!(outlined[Point::new(1, 0)], PixelColor::BLUE);
+        assert_eq!(outlined[Point::new(0, 1)], PixelColor::BLUE);
+        assert_eq!(outlined[Point::new(1, 1)], PixelColor::BLUE);
+        assert_eq!(outlined.to_mono_bits().count_ones(), 4);
+    }
+
+    #[test]
+    fn frame_line_is_displayed_as_a_hex_string() {
+        let mut bytes = [0u8; 128];
+        bytes[0] = 0xAB;
+        bytes[127] = 0x0C;
+        let hex = FrameLine::from_slice(&bytes).to_string();
+        assert_eq!(hex.len(), 256);
+        assert!(hex.starts_with("ab00"));
+        assert!(hex.ends_with("000c"));
+        assert_eq!(PixelFrame::WHITE.frame_line().to_string(), "ff".repeat(128));
+    }
+
+    #[test]
+    fn frame_line_from_serpentine_pixels_reverses_odd_rows() {
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (idx, px) in pixels.iter_mut().enumerate() {
+            *px = PixelColor::new((idx * 4) as u8, 0, 0);
+        }
+        let standard = FrameLine::from_pixels(&pixels).as_bytes();
+        let serpentine = FrameLine::from_pixels_serpentine(&pixels).as_bytes();
+        assert_eq!(serpentine[..16], standard[..16]);
+        for col in 0..8 {
+            let reversed = (7 - col) * 2;
+            assert_eq!(
+                serpentine[16 + col * 2..16 + col * 2 + 2],
+                standard[16 + reversed..16 + reversed + 2]
+            );
+        }
+        assert_eq!(serpentine[32..48], standard[32..48]);
+    }
+
+    #[cfg(feature = "rotate")]
+    #[test]
+    fn frame_line_flipped_180_matches_the_rotated_frame() {
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (idx, px) in pixels.iter_mut().enumerate() {
+            *px = PixelColor::new((idx * 4) as u8, 0, 255 - (idx * 4) as u8);
+        }
+        let rotated = PixelFrame::new(&pixels).rotate_180().frame_line();
+        let flipped = FrameLine::from_pixels_flipped_180(&pixels);
+        assert_eq!(flipped.as_bytes()[..], rotated.as_bytes()[..]);
+    }
+
+    #[test]
+    fn frame_layout_constants_match_the_led_matrix() {
+        assert_eq!(PixelFrame::WIDTH, 8);
+        assert_eq!(PixelFrame::HEIGHT, 8);
+        assert_eq!(
+            PixelFrame::PIXEL_COUNT,
+            PixelFrame::WIDTH * PixelFrame::HEIGHT
+        );
+        assert_eq!(
+            FrameLine::BYTE_LEN,
+            PixelFrame::BLACK.frame_line().as_bytes().len()
+        );
+    }
+
+    #[test]
+    fn frame_line_is_created_from_slice_of_bytes() {
+        let color: [u8; 128] = [0xE0; 128];
+        let frame_line = FrameLine::from_slice(&color);
+        frame_line
+            .as_bytes()
+            .iter()
+            .zip(color.iter())
+            .for_each(|(a, b)| {
+                assert_eq!(a, b);
+            });
+    }
+
+    #[cfg(not(feature = "big-endian"))]
+    #[test]
+    fn frame_line_is_created_from_slice_of_pixel_color() {
+        let blue = PixelColor::from_rgb565_bytes([0x1F, 0x00]);
+        let frame_line = FrameLine::from_pixels(&[blue; 64]);
+        frame_line.as_bytes().chunks(2).for_each(|chunk| {
+            assert_eq!([chunk[0], chunk[1]], [0x1F, 0x00]);
+        });
+    }
+
+    #[cfg(feature = "big-endian")]
+    #[test]
+    fn frame_line_is_created_from_slice_of_pixel_color() {
+        let blue = PixelColor::from_rgb565_bytes([0x00, 0x1F]);
+        let frame_line = FrameLine::from_pixels(&[blue; 64]);
+        frame_line.as_bytes().chunks(2).for_each(|chunk| {
+            assert_eq!([chunk[0], chunk[1]], [0x00, 0x1F]);
+        });
+    }
+
+    #[test]
+    fn pixel_frame_is_created_from_a_slice_of_pixel_color() {
+        let color_frame = [PixelColor::YELLOW; 64];
+        let pixel_frame = PixelFrame::new(&color_frame);
+        pixel_frame
+            .0
+            .iter()
+            .zip(color_frame.iter())
+            .for_each(|(a, b)| {
+                assert_eq!(a, b);
+            });
+    }
+
+    #[test]
+    fn pixel_frame_round_trips_through_a_vec_of_pixel_color() {
+        let pixel_frame = PixelFrame::new(PIXEL_FRAME);
+        let pixels: Vec<PixelColor> = pixel_frame.into();
+        assert_eq!(pixels, PIXEL_FRAME.to_vec());
+        assert_eq!(PixelFrame::from_vec(pixels).unwrap(), pixel_frame);
+    }
+
+    #[test]
+    fn pixel_frame_from_vec_of_the_wrong_length_fails() {
+        match PixelFrame::from_vec(vec![PixelColor::RED; 63]) {
+            Err(ScreenError::InvalidPixelCount { len }) => assert_eq!(len, 63),
+            other => panic!("expected an invalid pixel count, got {:?}", other),
+        }
+    }
+
+    #[test]
     fn pixel_frame_creates_a_frame_line_of_the_current_state() {
         let color_frame = [PixelColor::GREEN; 64];
         let pixel_frame = PixelFrame::new(&color_frame);
@@ -493,4 +2352,58 @@ mod tests {
         let pixel_frame = PixelFrame::new(PIXEL_FRAME);
         assert_eq!(PixelFrame::from_columns(&test_columns()), pixel_frame);
     }
+
+    #[test]
+    fn pixel_frame_average_color_is_the_mean_of_all_pixels() {
+        let mut pixels = [PixelColor::RED; 64];
+        for px in pixels[32..].iter_mut() {
+            *px = PixelColor::BLUE;
+        }
+        let pixel_frame = PixelFrame::new(&pixels);
+        assert_eq!(pixel_frame.average_color(), PixelColor::new(0x7F, 0, 0x7F));
+    }
+
+    #[test]
+    fn pixel_frame_dominant_color_is_the_most_frequent_pixel() {
+        let mut pixels = [PixelColor::GREEN; 64];
+        pixels[0] = PixelColor::RED;
+        pixels[9] = PixelColor::BLUE;
+        pixels[10] = PixelColor::BLUE;
+        let pixel_frame = PixelFrame::new(&pixels);
+        assert_eq!(pixel_frame.dominant_color(), PixelColor::GREEN);
+    }
+
+    #[test]
+    fn pixel_frame_is_round_tripped_through_mono_bits() {
+        const DIAGONAL: u64 = 0x8040_2010_0804_0201;
+        let pixel_frame = PixelFrame::from_mono_bits(DIAGONAL, PixelColor::RED, PixelColor::BLACK);
+        for (idx, px) in pixel_frame.0.iter().enumerate() {
+            if idx % 9 == 0 {
+                assert_eq!(*px, PixelColor::RED);
+            } else {
+                assert_eq!(*px, PixelColor::BLACK);
+            }
+        }
+        assert_eq!(pixel_frame.to_mono_bits(), DIAGONAL);
+    }
+
+    #[test]
+    fn pixel_frame_to_font_bytes_has_one_byte_per_row() {
+        let pixel_frame =
+            PixelFrame::from_mono_bits(0x8040_2010_0804_0201, PixelColor::RED, PixelColor::BLACK);
+        assert_eq!(
+            pixel_frame.to_font_bytes(),
+            [0x01, 0x02, 0x04, 0x08, 0x10, 0x20, 0x40, 0x80]
+        );
+    }
+
+    #[test]
+    fn pixel_frame_underscore_cursor_lights_the_bottom_row() {
+        let cursor = PixelFrame::underscore_cursor(PixelColor::GREEN);
+        let rows = cursor.as_rows();
+        assert_eq!(rows[7], [PixelColor::GREEN; 8]);
+        for row in rows[..7].iter() {
+            assert_eq!(row, &[PixelColor::BLACK; 8]);
+        }
+    }
 }
@@ -0,0 +1,141 @@
+//! Compact base64 encoding for `PixelFrame`s.
+//!
+//! Frames are encoded as the 128 RGB565 bytes of their `FrameLine`, using the URL-safe
+//! alphabet from [RFC 4648](https://tools.ietf.org/html/rfc4648#section-5), without padding.
+use super::{PixelColor, PixelFrame};
+use crate::error::ScreenError;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Methods enabled by the `base64` feature.
+impl PixelFrame {
+    /// Encode the RGB565 rendering of this `PixelFrame` as a base64 string.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate sensehat_screen;
+    /// # use sensehat_screen::{PixelColor, PixelFrame};
+    /// # fn main() {
+    ///     let frame = PixelFrame::new(&[PixelColor::new(0xF8, 0xFC, 0); 64]);
+    ///     let encoded = frame.to_base64();
+    ///     assert_eq!(encoded.len(), 171);
+    ///     assert_eq!(PixelFrame::from_base64(&encoded).unwrap(), frame);
+    /// # }
+    /// ```
+    pub fn to_base64(&self) -> String {
+        encode(&self.frame_line().as_bytes())
+    }
+
+    /// Decode a `PixelFrame` from a base64 string created with `PixelFrame::to_base64`.
+    ///
+    /// Both the URL-safe, and the standard alphabets are accepted, with or without padding.
+    /// The resulting frame has the color resolution of RGB565.
+    pub fn from_base64(encoded: &str) -> Result<PixelFrame, ScreenError> {
+        let bytes = decode(encoded)?;
+        if bytes.len() != 128 {
+            return Err(ScreenError::InvalidBase64);
+        }
+        let pixels = bytes.chunks(2).enumerate().fold(
+            [PixelColor::BLACK; 64],
+            |mut pixels, (idx, chunk)| {
+                pixels[idx] = PixelColor::from_rgb565_bytes([chunk[0], chunk[1]]);
+                pixels
+            },
+        );
+        Ok(PixelFrame::new(&pixels))
+    }
+}
+
+// Encode bytes with the URL-safe alphabet, without padding.
+fn encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len() / 3 * 4 + 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (idx, &b)| {
+            group | u32::from(b) << (16 - idx * 8)
+        });
+        for idx in 0..=chunk.len() {
+            let sextet = (group >> (18 - idx * 6)) & 0x3F;
+            encoded.push(char::from(ALPHABET[sextet as usize]));
+        }
+    }
+    encoded
+}
+
+// Decode bytes from the URL-safe, or standard alphabets, ignoring trailing padding.
+fn decode(encoded: &str) -> Result<Vec<u8>, ScreenError> {
+    fn sextet(c: u8) -> Result<u32, ScreenError> {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'-' | b'+' => 62,
+            b'_' | b'/' => 63,
+            _ => return Err(ScreenError::InvalidBase64),
+        };
+        Ok(u32::from(value))
+    }
+    let trimmed = encoded.trim_end_matches('=').as_bytes();
+    if trimmed.len() % 4 == 1 {
+        return Err(ScreenError::InvalidBase64);
+    }
+    let mut bytes = Vec::with_capacity(trimmed.len() * 3 / 4);
+    for chunk in trimmed.chunks(4) {
+        let mut group = 0u32;
+        for (idx, &c) in chunk.iter().enumerate() {
+            group |= sextet(c)? << (18 - idx * 6);
+        }
+        for idx in 0..chunk.len() - 1 {
+            bytes.push((group >> (16 - idx * 8)) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_encodes_bytes_with_url_safe_alphabet_without_padding() {
+        assert_eq!(encode(b"M"), "TQ");
+        assert_eq!(encode(b"Ma"), "TWE");
+        assert_eq!(encode(b"Man"), "TWFu");
+        assert_eq!(encode(&[0xFB, 0xFF]), "-_8");
+    }
+
+    #[test]
+    fn base64_decodes_bytes_with_or_without_padding() {
+        assert_eq!(decode("TWFu").unwrap(), b"Man".to_vec());
+        assert_eq!(decode("TWE=").unwrap(), b"Ma".to_vec());
+        assert_eq!(decode("TQ").unwrap(), b"M".to_vec());
+        assert_eq!(decode("+/8").unwrap(), vec![0xFB, 0xFF]);
+    }
+
+    #[test]
+    fn base64_decoding_fails_with_invalid_input() {
+        assert!(decode("T").is_err());
+        assert!(decode("T*Fu").is_err());
+    }
+
+    #[test]
+    fn pixel_frame_is_round_tripped_through_base64() {
+        let mut pixels = [PixelColor::new(0x12, 0x34, 0x56); 64];
+        pixels[0] = PixelColor::WHITE;
+        pixels[63] = PixelColor::new(0xAB, 0xCD, 0xEF);
+        let frame = PixelFrame::new(&pixels);
+        let quantized = frame.frame_line().as_bytes().chunks(2).enumerate().fold(
+            [PixelColor::BLACK; 64],
+            |mut pixels, (idx, chunk)| {
+                pixels[idx] = PixelColor::from_rgb565_bytes([chunk[0], chunk[1]]);
+                pixels
+            },
+        );
+        let decoded = PixelFrame::from_base64(&frame.to_base64()).unwrap();
+        assert_eq!(decoded, PixelFrame::new(&quantized));
+    }
+
+    #[test]
+    fn pixel_frame_from_base64_fails_with_wrong_byte_length() {
+        assert!(PixelFrame::from_base64("TWFu").is_err());
+    }
+}
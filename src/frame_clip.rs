@@ -3,10 +3,8 @@
 //! The `Clip` is the type that merges `PixelFrame` by rows or by columns
 //!
 //!
-use super::{
-    clip_pixel_frames_offset_bottom, clip_pixel_frames_offset_left, clip_pixel_frames_offset_right,
-    clip_pixel_frames_offset_top, Offset, PixelFrame,
-};
+use super::{slide_lines_backward, slide_lines_forward, FrameLine, Offset, PixelColor, PixelFrame};
+use crate::easing::Easing;
 
 /// Methods enabled by the `clip` feature.
 impl PixelFrame {
@@ -322,12 +320,24 @@ impl PixelFrame {
 pub struct Clip {
     first: PixelFrame,
     second: PixelFrame,
+    // The columns, and rows, of both frames, computed once to render every offset.
+    first_columns: [[PixelColor; 8]; 8],
+    second_columns: [[PixelColor; 8]; 8],
+    first_rows: [[PixelColor; 8]; 8],
+    second_rows: [[PixelColor; 8]; 8],
 }
 
 impl Clip {
     /// Create a new `Clip` from two `PixelFrame`s.
     pub fn new(first: PixelFrame, second: PixelFrame) -> Self {
-        Clip { first, second }
+        Clip {
+            first,
+            second,
+            first_columns: first.as_columns(),
+            second_columns: second.as_columns(),
+            first_rows: first.as_rows(),
+            second_rows: second.as_rows(),
+        }
     }
 
     /// Offset position for which to create the clipped `PixelFrame`.
@@ -340,28 +350,143 @@ impl Clip {
         }
     }
 
+    /// Offset position, which may be fractional, for which to create the clipped
+    /// `PixelFrame`, in the direction given by an `Offset` constructor, such as `Offset::left`.
+    ///
+    /// The frames at the two nearest integer offsets are blended by the fractional part of
+    /// the `position`, to fake sub-pixel scrolling. The `position` is clamped to the
+    /// `0.0..=8.0` range.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate sensehat_screen;
+    /// # use sensehat_screen::{Clip, Offset, PixelColor, PixelFrame};
+    /// # fn main() {
+    ///     let clip = Clip::new(PixelFrame::BLACK, PixelFrame::WHITE);
+    ///     assert_eq!(clip.offset_smooth(Offset::left, 2.0), clip.offset(Offset::left(2)));
+    ///     let halfway = clip.offset_smooth(Offset::left, 7.5);
+    ///     assert_eq!(halfway[0], PixelColor::new(128, 128, 128));
+    /// # }
+    /// ```
+    pub fn offset_smooth(&self, offset: fn(u8) -> Offset, position: f32) -> PixelFrame {
+        let position = position.max(0.0).min(8.0);
+        let floor = position.floor();
+        let lower = self.offset(offset(floor as u8));
+        let fraction = position - floor;
+        if fraction == 0.0 {
+            return lower;
+        }
+        let upper = self.offset(offset(floor as u8 + 1));
+        let mut blended = lower;
+        for idx in 0..64 {
+            blended[idx] = lower[idx].lerp(&upper[idx], fraction);
+        }
+        blended
+    }
+
+    /// Returns the 9 `FrameLine`s of the clip, at the offsets from `0` to `8`, in the
+    /// direction given by an `Offset` constructor, such as `Offset::left`.
+    ///
+    /// The rows, or columns, of both frames are only computed once for every offset.
+    pub fn frame_lines(&self, offset: fn(u8) -> Offset) -> Vec<FrameLine> {
+        let (first, second, by_rows) = match offset(0) {
+            Offset::Left(_) | Offset::Right(_) => {
+                (&self.first_columns, &self.second_columns, false)
+            }
+            Offset::Top(_) | Offset::Bottom(_) => (&self.first_rows, &self.second_rows, true),
+        };
+        // The 16 lines that slide across the screen, and where the offset `0` starts.
+        let mut strip = [[PixelColor::BLACK; 8]; 16];
+        let forward = match offset(0) {
+            Offset::Left(_) | Offset::Top(_) => {
+                strip[..8].copy_from_slice(first);
+                strip[8..].copy_from_slice(second);
+                true
+            }
+            Offset::Right(_) | Offset::Bottom(_) => {
+                strip[..8].copy_from_slice(second);
+                strip[8..].copy_from_slice(first);
+                false
+            }
+        };
+        (0..9)
+            .map(|n| {
+                let start = if forward { n } else { 8 - n };
+                let mut lines = [[PixelColor::BLACK; 8]; 8];
+                lines.copy_from_slice(&strip[start..start + 8]);
+                let frame = if by_rows {
+                    PixelFrame::from_rows(&lines)
+                } else {
+                    PixelFrame::from_columns(&lines)
+                };
+                frame.frame_line()
+            })
+            .collect()
+    }
+
+    /// Like `Clip::offset_smooth`, with the `position` reshaped by the `easing`, as the
+    /// progress of the full `0.0..=8.0` range.
+    pub fn offset_smooth_eased(
+        &self,
+        offset: fn(u8) -> Offset,
+        position: f32,
+        easing: Easing,
+    ) -> PixelFrame {
+        self.offset_smooth(offset, easing.apply(position / 8.0) * 8.0)
+    }
+
     // # Panics
     // If `offset` is out of bounds (> 8).
     fn offset_left(&self, offset: u8) -> PixelFrame {
         assert!(offset < 9);
-        clip_pixel_frames_offset_left(self.first, self.second, offset)
+        match offset as usize {
+            0 => self.first,
+            8 => self.second,
+            n => PixelFrame::from_columns(&slide_lines_forward(
+                &self.first_columns,
+                &self.second_columns,
+                n,
+            )),
+        }
     }
 
     // # Panics
     // If `offset` is out of bounds (> 8).
     fn offset_right(&self, offset: u8) -> PixelFrame {
         assert!(offset < 9);
-        clip_pixel_frames_offset_right(self.first, self.second, offset)
+        match offset as usize {
+            0 => self.first,
+            8 => self.second,
+            n => PixelFrame::from_columns(&slide_lines_backward(
+                &self.first_columns,
+                &self.second_columns,
+                n,
+            )),
+        }
     }
 
     fn offset_bottom(&self, offset: u8) -> PixelFrame {
         assert!(offset < 9);
-        clip_pixel_frames_offset_bottom(self.first, self.second, offset)
+        match offset as usize {
+            0 => self.first,
+            8 => self.second,
+            n => PixelFrame::from_rows(&slide_lines_backward(
+                &self.first_rows,
+                &self.second_rows,
+                n,
+            )),
+        }
     }
 
     fn offset_top(&self, offset: u8) -> PixelFrame {
         assert!(offset < 9);
-        clip_pixel_frames_offset_top(self.first, self.second, offset)
+        match offset as usize {
+            0 => self.first,
+            8 => self.second,
+            n => {
+                PixelFrame::from_rows(&slide_lines_forward(&self.first_rows, &self.second_rows, n))
+            }
+        }
     }
 }
 
@@ -744,6 +869,116 @@ mod tests {
         assert_eq!(clip.offset(Offset::left(8)), symbol_two);
     }
 
+    #[test]
+    fn frame_clip_smooth_offsets_match_integer_offsets() {
+        let clip = PixelFrame::new(&FRAME_ONE).build_clip(&PixelFrame::new(&FRAME_TWO));
+        let constructors: [fn(u8) -> Offset; 4] =
+            [Offset::left, Offset::right, Offset::bottom, Offset::top];
+        for &constructor in constructors.iter() {
+            for position in 0..9 {
+                assert_eq!(
+                    clip.offset_smooth(constructor, f32::from(position)),
+                    clip.offset(constructor(position))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn frame_clip_smooth_offsets_blend_between_integer_offsets() {
+        let clip = PixelFrame::BLACK.build_clip(&PixelFrame::new(&[BLUE; 64]));
+        let frame = clip.offset_smooth(Offset::right, 0.25);
+        assert_eq!(frame[0], PixelColor::new(0, 0, 64));
+        assert_eq!(frame[1], DARK);
+        assert_eq!(
+            clip.offset_smooth(Offset::right, 12.0),
+            clip.offset(Offset::right(8))
+        );
+    }
+
+    #[test]
+    fn frame_clip_frame_lines_match_every_offset() {
+        let clip = PixelFrame::new(&FRAME_ONE).build_clip(&PixelFrame::new(&FRAME_TWO));
+        let constructors: [fn(u8) -> Offset; 4] =
+            [Offset::left, Offset::right, Offset::bottom, Offset::top];
+        for &constructor in constructors.iter() {
+            let lines = clip.frame_lines(constructor);
+            assert_eq!(lines.len(), 9);
+            for (position, line) in lines.iter().enumerate() {
+                assert_eq!(*line, clip.offset(constructor(position as u8)).frame_line());
+            }
+        }
+    }
+
+    // The pixel at `idx` of the clip at `offset`, from the frames laid side-by-side.
+    fn clipped_pixel(
+        first: &PixelFrame,
+        second: &PixelFrame,
+        offset: Offset,
+        idx: usize,
+    ) -> PixelColor {
+        let (row, col) = (idx / 8, idx % 8);
+        // The position along the two frames, where `0..8` is the first frame.
+        let (along, across, horizontal) = match offset {
+            Offset::Left(n) => (col + usize::from(n), row, true),
+            Offset::Right(n) => (col + 8 - usize::from(n), row, true),
+            Offset::Top(n) => (row + usize::from(n), col, false),
+            Offset::Bottom(n) => (row + 8 - usize::from(n), col, false),
+        };
+        let (leading, trailing) = match offset {
+            Offset::Left(_) | Offset::Top(_) => (first, second),
+            Offset::Right(_) | Offset::Bottom(_) => (second, first),
+        };
+        let (frame, along) = if along < 8 {
+            (leading, along)
+        } else {
+            (trailing, along - 8)
+        };
+        if horizontal {
+            frame[across * 8 + along]
+        } else {
+            frame[along * 8 + across]
+        }
+    }
+
+    #[test]
+    fn frame_clip_with_cached_lines_matches_the_frames_side_by_side() {
+        let (first, second) = (PixelFrame::new(&FRAME_ONE), PixelFrame::new(&FRAME_TWO));
+        let clip = first.build_clip(&second);
+        assert_eq!(clip.first_columns, first.as_columns());
+        assert_eq!(clip.second_rows, second.as_rows());
+        let constructors: [fn(u8) -> Offset; 4] =
+            [Offset::left, Offset::right, Offset::bottom, Offset::top];
+        for &constructor in constructors.iter() {
+            for n in 0..9 {
+                let frame = clip.offset(constructor(n));
+                for idx in 0..64 {
+                    assert_eq!(
+                        frame[idx],
+                        clipped_pixel(&first, &second, constructor(n), idx)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn frame_clip_eased_smooth_offsets_reshape_the_position() {
+        let clip = PixelFrame::new(&FRAME_ONE).build_clip(&PixelFrame::new(&FRAME_TWO));
+        assert_eq!(
+            clip.offset_smooth_eased(Offset::left, 4.0, Easing::EaseInQuad),
+            clip.offset(Offset::left(2))
+        );
+        assert_eq!(
+            clip.offset_smooth_eased(Offset::left, 4.0, Easing::EaseInOutCubic),
+            clip.offset(Offset::left(4))
+        );
+        assert_eq!(
+            clip.offset_smooth_eased(Offset::top, 8.0, Easing::EaseOutQuad),
+            clip.offset(Offset::top(8))
+        );
+    }
+
     #[test]
     #[should_panic]
     fn frame_clip_panics_when_offset_to_the_left_is_greater_than_8() {
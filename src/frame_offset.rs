@@ -1,9 +1,18 @@
 //! `PixelFrame` offset in left/right/top/bottom directions.
 use super::{
     clip_pixel_frames_offset_bottom, clip_pixel_frames_offset_left, clip_pixel_frames_offset_right,
-    clip_pixel_frames_offset_top, Offset, PixelFrame,
+    clip_pixel_frames_offset_top, Offset, PixelColor, PixelFrame,
 };
 
+/// The axis along which `PixelFrame::offset_saturating` moves a frame.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Axis {
+    /// Left, for negative signs, or right, for positive signs.
+    Horizontal,
+    /// Up, for negative signs, or down, for positive signs.
+    Vertical,
+}
+
 /// Methods enabled by the `offset` feature.
 impl PixelFrame {
     /// Offset the PixelFrame by a number of pixels in any of the possible directions:
@@ -31,6 +40,69 @@ impl PixelFrame {
         }
     }
 
+    /// Offset the PixelFrame by any number of pixels along an `Axis`, in the direction given
+    /// by the sign of `direction_sign`. A `direction_sign` of `0` leaves the frame unchanged.
+    ///
+    /// Unlike `PixelFrame::offset`, this never panics: moving by `8` pixels, or more, moves
+    /// the frame completely off-screen, and returns an all-black frame.
+    pub fn offset_saturating(&self, direction_sign: i8, axis: Axis, amount: u32) -> Self {
+        let amount = amount.min(8) as u8;
+        match (axis, direction_sign.signum()) {
+            (_, 0) => *self,
+            (Axis::Horizontal, -1) => self.offset_left(amount),
+            (Axis::Horizontal, _) => self.offset_right(amount),
+            (Axis::Vertical, -1) => self.offset_top(amount),
+            (Axis::Vertical, _) => self.offset_bottom(amount),
+        }
+    }
+
+    /// Offset the PixelFrame horizontally and vertically at once, by `dx` pixels to the
+    /// right, and `dy` pixels down. Negative values move the frame to the left, and up.
+    ///
+    /// The vacated pixels are filled with the `fill` color, and shifts of `8` pixels, or
+    /// more, move the frame completely off-screen.
+    pub fn offset_xy(&self, dx: i8, dy: i8, fill: PixelColor) -> Self {
+        let mut frame = PixelFrame::new(&[fill; 64]);
+        let (dx, dy) = (i16::from(dx), i16::from(dy));
+        for idx in 0..64 {
+            let (row, col) = (idx as i16 / 8, idx as i16 % 8);
+            if let (0..=7, 0..=7) = (row - dy, col - dx) {
+                frame[idx] = self[((row - dy) * 8 + col - dx) as usize];
+            }
+        }
+        frame
+    }
+
+    /// Translate the PixelFrame by any signed amount of pixels, like `PixelFrame::offset_xy`,
+    /// for positions driven by velocities that may go far off-screen in either direction.
+    ///
+    /// Shifts of `8` pixels, or more, in either direction yield a frame filled with `fill`.
+    pub fn translate(&self, dx: i32, dy: i32, fill: PixelColor) -> Self {
+        let clamp = |delta: i32| delta.max(-8).min(8) as i8;
+        self.offset_xy(clamp(dx), clamp(dy), fill)
+    }
+
+    /// Offset the PixelFrame like `PixelFrame::offset`, but fill the vacated columns, or
+    /// rows, with a mirrored copy of the original edge, as if the content bounced off it.
+    ///
+    /// # Panics
+    ///
+    /// If `offset` is out of bounds (> 8).
+    pub fn offset_mirror(&self, offset: Offset) -> Self {
+        let mut frame = PixelFrame::default();
+        for idx in 0..64 {
+            let (row, col) = (idx / 8, idx % 8);
+            let (row, col) = match offset {
+                Offset::Left(offset) => (row, 7 - mirror_source(7 - col, offset)),
+                Offset::Right(offset) => (row, mirror_source(col, offset)),
+                Offset::Top(offset) => (7 - mirror_source(7 - row, offset), col),
+                Offset::Bottom(offset) => (mirror_source(row, offset), col),
+            };
+            frame[idx] = self[row * 8 + col];
+        }
+        frame
+    }
+
     // # Panics
     // If `offset` is out of bounds (> 8).
     fn offset_left(&self, offset: u8) -> Self {
@@ -54,6 +126,18 @@ impl PixelFrame {
     }
 }
 
+// The original line shown at `line`, after moving the content `offset` lines towards the
+// higher indices, and reflecting it at the lower edge.
+fn mirror_source(line: usize, offset: u8) -> usize {
+    assert!(offset < 9);
+    let offset = usize::from(offset);
+    if line < offset {
+        offset - 1 - line
+    } else {
+        line - offset
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -434,6 +518,43 @@ mod tests {
         assert_eq!(symbol.offset(Offset::left(8)), PixelFrame::new(&FRAME_ZERO));
     }
 
+    #[test]
+    fn pixel_frame_offset_saturating_matches_offset_within_bounds() {
+        let symbol = PixelFrame::new(&SYMBOL_FRAME);
+        assert_eq!(
+            symbol.offset_saturating(-1, Axis::Horizontal, 3),
+            symbol.offset(Offset::left(3))
+        );
+        assert_eq!(
+            symbol.offset_saturating(1, Axis::Horizontal, 3),
+            symbol.offset(Offset::right(3))
+        );
+        assert_eq!(
+            symbol.offset_saturating(-5, Axis::Vertical, 3),
+            symbol.offset(Offset::top(3))
+        );
+        assert_eq!(
+            symbol.offset_saturating(5, Axis::Vertical, 3),
+            symbol.offset(Offset::bottom(3))
+        );
+        assert_eq!(symbol.offset_saturating(0, Axis::Vertical, 3), symbol);
+    }
+
+    #[test]
+    fn pixel_frame_offset_saturating_moves_off_screen_from_8_pixels() {
+        let symbol = PixelFrame::new(&SYMBOL_FRAME);
+        for &amount in &[8, 100, 1_000_000] {
+            for &sign in &[-1, 1] {
+                for &axis in &[Axis::Horizontal, Axis::Vertical] {
+                    assert_eq!(
+                        symbol.offset_saturating(sign, axis, amount),
+                        PixelFrame::new(&FRAME_ZERO)
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     #[should_panic]
     fn pixel_frame_panics_when_offset_to_the_left_is_greater_than_8() {
@@ -581,4 +702,79 @@ mod tests {
         let symbol = PixelFrame::new(&SYMBOL_FRAME);
         let _ = symbol.offset(Offset::top(9));
     }
+
+    #[test]
+    fn pixel_frame_offset_mirror_to_the_right_reflects_the_left_edge() {
+        let symbol = PixelFrame::new(&SYMBOL_FRAME);
+        let mirrored = symbol.offset_mirror(Offset::right(3));
+        let (original, mirrored) = (symbol.as_columns(), mirrored.as_columns());
+        assert_eq!(mirrored[0], original[2]);
+        assert_eq!(mirrored[1], original[1]);
+        assert_eq!(mirrored[2], original[0]);
+        assert_eq!(mirrored[3..], original[..5]);
+    }
+
+    #[test]
+    fn pixel_frame_offset_mirror_upwards_reflects_the_bottom_edge() {
+        let symbol = PixelFrame::new(&SYMBOL_FRAME);
+        let mirrored = symbol.offset_mirror(Offset::top(2)).as_rows();
+        let original = symbol.as_rows();
+        assert_eq!(mirrored[..6], original[2..]);
+        assert_eq!(mirrored[6], original[7]);
+        assert_eq!(mirrored[7], original[6]);
+    }
+
+    #[test]
+    fn pixel_frame_offset_mirror_by_zero_or_eight() {
+        let symbol = PixelFrame::new(&SYMBOL_FRAME);
+        assert_eq!(symbol.offset_mirror(Offset::left(0)), symbol);
+        let mut flipped = symbol;
+        flipped.flip_v();
+        assert_eq!(symbol.offset_mirror(Offset::bottom(8)), flipped);
+    }
+
+    #[test]
+    fn pixel_frame_offset_xy_along_one_axis_matches_offset() {
+        let symbol = PixelFrame::new(&SYMBOL_FRAME);
+        assert_eq!(
+            symbol.offset_xy(2, 0, DARK),
+            symbol.offset(Offset::right(2))
+        );
+        assert_eq!(
+            symbol.offset_xy(-3, 0, DARK),
+            symbol.offset(Offset::left(3))
+        );
+        assert_eq!(
+            symbol.offset_xy(0, 4, DARK),
+            symbol.offset(Offset::bottom(4))
+        );
+        assert_eq!(symbol.offset_xy(0, -5, DARK), symbol.offset(Offset::top(5)));
+        assert_eq!(symbol.offset_xy(0, 0, DARK), symbol);
+    }
+
+    #[test]
+    fn pixel_frame_offset_xy_moves_diagonally_and_fills_once() {
+        let symbol = PixelFrame::new(&SYMBOL_FRAME);
+        let moved = symbol.offset_xy(2, 2, PixelColor::RED);
+        let (rows, original) = (moved.as_rows(), symbol.as_rows());
+        assert_eq!(rows[0], [PixelColor::RED; 8]);
+        assert_eq!(rows[1], [PixelColor::RED; 8]);
+        for row in 2..8 {
+            assert_eq!(rows[row][..2], [PixelColor::RED; 2]);
+            assert_eq!(rows[row][2..], original[row - 2][..6]);
+        }
+        assert_eq!(symbol.offset_xy(-8, 127, PixelColor::RED), PixelFrame::RED);
+    }
+
+    #[test]
+    fn pixel_frame_translates_by_any_signed_amount() {
+        let symbol = PixelFrame::new(&SYMBOL_FRAME);
+        assert_eq!(
+            symbol.translate(-3, 0, DARK),
+            symbol.offset(Offset::left(3))
+        );
+        assert_eq!(symbol.translate(100, 0, PixelColor::RED), PixelFrame::RED);
+        assert_eq!(symbol.translate(0, -9000, PixelColor::RED), PixelFrame::RED);
+        assert_eq!(symbol.translate(0, 0, DARK), symbol);
+    }
 }
@@ -0,0 +1,90 @@
+//! Frame rate limiting for the LED Matrix screen
+use super::PixelFrame;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// An iterator of `PixelFrame`s that sleeps between frames, to yield them at a target rate.
+///
+/// The first frame is yielded immediately, and every following frame waits until its
+/// deadline. Time spent by the caller between frames counts towards the wait.
+#[derive(Debug)]
+pub struct ThrottledFrames<I> {
+    frames: I,
+    interval: Duration,
+    deadline: Option<Instant>,
+}
+
+impl<I: Iterator<Item = PixelFrame>> ThrottledFrames<I> {
+    /// Create a new `ThrottledFrames` that yields the `frames` at `fps` frames per second.
+    ///
+    /// # Panics
+    /// If `fps` is `0`.
+    pub fn new(frames: I, fps: u32) -> Self {
+        assert!(fps > 0);
+        ThrottledFrames {
+            frames,
+            interval: Duration::from_secs(1) / fps,
+            deadline: None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = PixelFrame>> Iterator for ThrottledFrames<I> {
+    type Item = PixelFrame;
+
+    fn next(&mut self) -> Option<PixelFrame> {
+        if let Some(deadline) = self.deadline {
+            let now = Instant::now();
+            if deadline > now {
+                thread::sleep(deadline - now);
+            }
+        }
+        let frame = self.frames.next()?;
+        let start = self.deadline.unwrap_or_else(Instant::now);
+        self.deadline = Some(start + self.interval);
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.frames.size_hint()
+    }
+}
+
+/// Extension trait to throttle any iterator of `PixelFrame`s.
+pub trait Throttle: Iterator<Item = PixelFrame> + Sized {
+    /// Yield the frames at `fps` frames per second.
+    ///
+    /// # Panics
+    /// If `fps` is `0`.
+    fn throttle(self, fps: u32) -> ThrottledFrames<Self> {
+        ThrottledFrames::new(self, fps)
+    }
+}
+
+impl<I: Iterator<Item = PixelFrame>> Throttle for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttled_frames_pass_through_unchanged() {
+        let frames = vec![PixelFrame::RED, PixelFrame::GREEN, PixelFrame::BLUE];
+        let throttled: Vec<PixelFrame> = frames.clone().into_iter().throttle(1000).collect();
+        assert_eq!(throttled, frames);
+    }
+
+    #[test]
+    fn throttled_frames_wait_between_frames() {
+        let start = Instant::now();
+        let count = vec![PixelFrame::RED; 3].into_iter().throttle(100).count();
+        assert_eq!(count, 3);
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    #[should_panic]
+    fn throttled_frames_need_a_positive_rate() {
+        let _ = vec![PixelFrame::RED].into_iter().throttle(0);
+    }
+}
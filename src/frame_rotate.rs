@@ -24,23 +24,25 @@ impl PixelFrame {
         }
     }
 
-    // Rotate the display to the left by 90 degrees. Creates a new `PixelFrame`.
-    fn rotate_left(&self) -> Self {
+    /// Create a new `PixelFrame` that is rotated to the left by `90°`, leaving this frame
+    /// unchanged.
+    pub fn rotate_left(&self) -> Self {
         let mut flip_left = *self;
         flip_left.flip_h();
         flip_left.transpose();
         flip_left
     }
 
-    // Rotate the display by 180 degrees. Creates a new `PixelFrame`.
-    fn rotate_180(&self) -> Self {
+    /// Create a new `PixelFrame` that is rotated by `180°`, leaving this frame unchanged.
+    pub fn rotate_180(&self) -> Self {
         let mut flip_180 = *self;
         flip_180.reverse();
         flip_180
     }
 
-    // Rotate the display to the right by 90 degrees. Creates a new `PixelFrame`.
-    fn rotate_right(&self) -> Self {
+    /// Create a new `PixelFrame` that is rotated to the right by `90°`, leaving this frame
+    /// unchanged.
+    pub fn rotate_right(&self) -> Self {
         let mut flip_right = *self;
         flip_right.transpose();
         flip_right.flip_h();
@@ -127,4 +129,22 @@ mod tests {
         let checker_right = PixelFrame(CHECKER_90_CW);
         assert_eq!(checker_base.rotate_right(), checker_right);
     }
+
+    #[test]
+    fn pixel_frame_rotations_are_distinct_and_leave_the_original_unchanged() {
+        let checker_base = PixelFrame(CHECKER_BASE);
+        let rotated = [
+            checker_base.rotate(Rotate::Ccw90),
+            checker_base.rotate(Rotate::Ccw180),
+            checker_base.rotate(Rotate::Ccw270),
+        ];
+        assert_eq!(checker_base, PixelFrame(CHECKER_BASE));
+        assert_eq!(checker_base.rotate(Rotate::None), checker_base);
+        assert_eq!(rotated[0], PixelFrame(CHECKER_90_CCW));
+        assert_eq!(rotated[1], PixelFrame(CHECKER_180));
+        assert_eq!(rotated[2], PixelFrame(CHECKER_90_CW));
+        assert_ne!(rotated[0], rotated[1]);
+        assert_ne!(rotated[1], rotated[2]);
+        assert_ne!(rotated[0], rotated[2]);
+    }
 }
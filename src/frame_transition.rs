@@ -0,0 +1,363 @@
+//! Frame transitions for the LED Matrix screen
+use super::{PixelColor, PixelFrame, XorShift};
+use crate::easing::Easing;
+
+/// The direction in which a wipe transition reveals the next `PixelFrame`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WipeDirection {
+    LeftToRight,
+    RightToLeft,
+    TopToBottom,
+    BottomToTop,
+}
+
+impl PixelFrame {
+    /// Create an iterator of `steps + 1` frames that progressively reveal `to` over this
+    /// frame, one line at a time, in the given direction.
+    ///
+    /// The first frame is this frame, and the last frame is `to`. When `steps` is `0`, the
+    /// only frame is `to`.
+    pub fn wipe_to(
+        &self,
+        to: &PixelFrame,
+        direction: WipeDirection,
+        steps: usize,
+    ) -> impl Iterator<Item = PixelFrame> {
+        let mut ranks = [0usize; 64];
+        for (idx, rank) in ranks.iter_mut().enumerate() {
+            let (row, col) = (idx / 8, idx % 8);
+            *rank = match direction {
+                WipeDirection::LeftToRight => col,
+                WipeDirection::RightToLeft => 7 - col,
+                WipeDirection::TopToBottom => row,
+                WipeDirection::BottomToTop => 7 - row,
+            };
+        }
+        reveal(*self, *to, ranks, 8, steps, Easing::Linear)
+    }
+
+    /// Create an iterator of `steps + 1` frames that progressively reveal `to` over this
+    /// frame, one pixel at a time, in a clockwise spiral from the top-left corner towards
+    /// the center.
+    ///
+    /// The first frame is this frame, and the last frame is `to`. When `steps` is `0`, the
+    /// only frame is `to`.
+    pub fn spiral_to(&self, to: &PixelFrame, steps: usize) -> impl Iterator<Item = PixelFrame> {
+        reveal(*self, *to, spiral_ranks(), 64, steps, Easing::Linear)
+    }
+
+    /// Create an iterator of `steps + 1` frames that progressively reveal `to` over this
+    /// frame, one pixel at a time, in a pseudo-random order driven by `seed`. The same
+    /// `seed` always produces the same dissolve.
+    ///
+    /// The first frame is this frame, and the last frame is `to`. When `steps` is `0`, the
+    /// only frame is `to`.
+    pub fn dissolve_to(
+        &self,
+        to: &PixelFrame,
+        steps: usize,
+        seed: u64,
+    ) -> impl Iterator<Item = PixelFrame> {
+        self.dissolve_to_eased(to, steps, seed, Easing::Linear)
+    }
+
+    /// Like `PixelFrame::dissolve_to`, with the number of revealed pixels at each step
+    /// reshaped by the `easing`.
+    pub fn dissolve_to_eased(
+        &self,
+        to: &PixelFrame,
+        steps: usize,
+        seed: u64,
+        easing: Easing,
+    ) -> impl Iterator<Item = PixelFrame> {
+        reveal(*self, *to, shuffled_ranks(seed), 64, steps, easing)
+    }
+
+    /// Create an iterator of 8 frames that reveal this frame one row at a time, from the
+    /// top, over a `bg` background, like a CRT powering on.
+    ///
+    /// The first frame shows only the top row, and the last frame is this frame.
+    pub fn scanline_reveal(&self, bg: PixelColor) -> impl Iterator<Item = PixelFrame> {
+        // The first wipe frame is all `bg`, so it is skipped.
+        PixelFrame([bg; 64])
+            .wipe_to(self, WipeDirection::TopToBottom, 8)
+            .skip(1)
+    }
+
+    /// Create an iterator of 9 frames that reveal this frame one column at a time, over a
+    /// `bg` background, starting from the left column if `from_left` is `true`, or from the
+    /// right column otherwise.
+    ///
+    /// The first frame is all `bg`, and the last frame is this frame.
+    pub fn reveal_columns(
+        &self,
+        bg: PixelColor,
+        from_left: bool,
+    ) -> impl Iterator<Item = PixelFrame> {
+        let direction = if from_left {
+            WipeDirection::LeftToRight
+        } else {
+            WipeDirection::RightToLeft
+        };
+        PixelFrame([bg; 64]).wipe_to(self, direction, 8)
+    }
+
+    /// Create an iterator of `steps + 1` frames that blend the color of every pixel from
+    /// this frame into `to`, with the blending progress reshaped by the `easing`.
+    ///
+    /// The first frame is this frame, and the last frame is `to`. When `steps` is `0`, the
+    /// only frame is `to`.
+    pub fn crossfade(
+        &self,
+        to: &PixelFrame,
+        steps: usize,
+        easing: Easing,
+    ) -> impl Iterator<Item = PixelFrame> {
+        let (from, to) = (*self, *to);
+        progress(steps).map(move |t| {
+            let t = easing.apply(t);
+            let mut frame = from;
+            for idx in 0..64 {
+                frame[idx] = from[idx].lerp(&to[idx], t);
+            }
+            frame
+        })
+    }
+}
+
+// Yield `steps + 1` frames, where the pixels of `to` with a rank below the step's threshold
+// replace the pixels of `from`. Ranks are in the `0..levels` range, and the threshold grows
+// with the eased progress.
+pub(crate) fn reveal(
+    from: PixelFrame,
+    to: PixelFrame,
+    ranks: [usize; 64],
+    levels: usize,
+    steps: usize,
+    easing: Easing,
+) -> impl Iterator<Item = PixelFrame> {
+    progress(steps).map(move |t| {
+        let threshold = (levels as f32 * easing.apply(t)).floor() as usize;
+        let mut frame = from;
+        for (idx, &rank) in ranks.iter().enumerate() {
+            if rank < threshold {
+                frame[idx] = to[idx];
+            }
+        }
+        frame
+    })
+}
+
+// The progress, from `0.0` to `1.0`, of each of the `steps + 1` frames of a transition, or
+// only `1.0` when `steps` is `0`.
+fn progress(steps: usize) -> impl Iterator<Item = f32> {
+    let first = if steps == 0 { 1 } else { 0 };
+    let divisor = steps.max(1);
+    (first..=divisor).map(move |step| step as f32 / divisor as f32)
+}
+
+// The position of each pixel along a clockwise spiral, from the top-left corner inwards.
+fn spiral_ranks() -> [usize; 64] {
+    let mut ranks = [0usize; 64];
+    let mut rank = 0;
+    for ring in 0..4 {
+        let (first, last) = (ring, 7 - ring);
+        let path = (first..=last)
+            .map(|col| (first, col))
+            .chain((first + 1..=last).map(|row| (row, last)))
+            .chain((first..last).rev().map(|col| (last, col)))
+            .chain((first + 1..last).rev().map(|row| (row, first)));
+        for (row, col) in path {
+            ranks[row * 8 + col] = rank;
+            rank += 1;
+        }
+    }
+    ranks
+}
+
+// A permutation of the `0..64` ranks, shuffled with a xorshift generator seeded by `seed`.
+fn shuffled_ranks(seed: u64) -> [usize; 64] {
+    let mut ranks = [0usize; 64];
+    for (idx, rank) in ranks.iter_mut().enumerate() {
+        *rank = idx;
+    }
+    let mut rng = XorShift::new(seed);
+    for idx in (1..64).rev() {
+        ranks.swap(idx, (rng.next_u64() % (idx as u64 + 1)) as usize);
+    }
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PixelColor;
+
+    #[test]
+    fn wipe_left_to_right_starts_with_self_and_ends_with_to() {
+        let frames: Vec<PixelFrame> = PixelFrame::RED
+            .wipe_to(&PixelFrame::BLUE, WipeDirection::LeftToRight, 4)
+            .collect();
+        assert_eq!(frames.len(), 5);
+        assert_eq!(frames[0], PixelFrame::RED);
+        assert_eq!(frames[4], PixelFrame::BLUE);
+    }
+
+    #[test]
+    fn wipe_left_to_right_reveals_the_leftmost_columns_first() {
+        let halfway = PixelFrame::RED
+            .wipe_to(&PixelFrame::BLUE, WipeDirection::LeftToRight, 2)
+            .nth(1)
+            .unwrap();
+        for row in halfway.as_rows().iter() {
+            assert_eq!(row[..4], [PixelColor::BLUE; 4]);
+            assert_eq!(row[4..], [PixelColor::RED; 4]);
+        }
+    }
+
+    #[test]
+    fn wipe_bottom_to_top_reveals_the_bottom_row_first() {
+        let first_row = PixelFrame::RED
+            .wipe_to(&PixelFrame::BLUE, WipeDirection::BottomToTop, 8)
+            .nth(1)
+            .unwrap();
+        let rows = first_row.as_rows();
+        assert_eq!(rows[7], [PixelColor::BLUE; 8]);
+        assert_eq!(rows[6], [PixelColor::RED; 8]);
+    }
+
+    #[test]
+    fn spiral_ranks_visit_every_pixel_once() {
+        let ranks = spiral_ranks();
+        let mut sorted = ranks.to_vec();
+        sorted.sort();
+        assert_eq!(sorted, (0..64).collect::<Vec<usize>>());
+        assert_eq!(ranks[0..8], [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(ranks[15], 8);
+        assert_eq!(ranks[8], 27);
+    }
+
+    #[test]
+    fn spiral_starts_with_self_and_ends_with_to() {
+        let frames: Vec<PixelFrame> = PixelFrame::RED.spiral_to(&PixelFrame::BLUE, 64).collect();
+        assert_eq!(frames.len(), 65);
+        assert_eq!(frames[0], PixelFrame::RED);
+        assert_eq!(frames[1][0], PixelColor::BLUE);
+        assert_eq!(frames[1][1], PixelColor::RED);
+        assert_eq!(frames[64], PixelFrame::BLUE);
+    }
+
+    #[test]
+    fn dissolve_starts_with_self_and_ends_with_to() {
+        let frames: Vec<PixelFrame> = PixelFrame::RED
+            .dissolve_to(&PixelFrame::BLUE, 16, 42)
+            .collect();
+        assert_eq!(frames.len(), 17);
+        assert_eq!(frames[0], PixelFrame::RED);
+        assert_eq!(frames[16], PixelFrame::BLUE);
+    }
+
+    #[test]
+    fn dissolve_with_the_same_seed_is_reproduced() {
+        let first: Vec<PixelFrame> = PixelFrame::RED
+            .dissolve_to(&PixelFrame::BLUE, 8, 7)
+            .collect();
+        let second: Vec<PixelFrame> = PixelFrame::RED
+            .dissolve_to(&PixelFrame::BLUE, 8, 7)
+            .collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn shuffled_ranks_are_a_permutation_that_depends_on_the_seed() {
+        for &seed in &[0, 1, 42, !0] {
+            let mut sorted = shuffled_ranks(seed).to_vec();
+            sorted.sort();
+            assert_eq!(sorted, (0..64).collect::<Vec<usize>>());
+        }
+        assert_ne!(shuffled_ranks(1)[..], shuffled_ranks(2)[..]);
+    }
+
+    #[test]
+    fn eased_dissolve_reveals_fewer_pixels_halfway_with_ease_in() {
+        let count_blue = |frame: &PixelFrame| {
+            (0..64)
+                .filter(|&idx| frame[idx] == PixelColor::BLUE)
+                .count()
+        };
+        let linear = PixelFrame::RED
+            .dissolve_to_eased(&PixelFrame::BLUE, 2, 3, Easing::Linear)
+            .nth(1)
+            .unwrap();
+        let eased = PixelFrame::RED
+            .dissolve_to_eased(&PixelFrame::BLUE, 2, 3, Easing::EaseInQuad)
+            .nth(1)
+            .unwrap();
+        assert_eq!(count_blue(&linear), 32);
+        assert_eq!(count_blue(&eased), 16);
+    }
+
+    #[test]
+    fn crossfade_blends_from_self_to_to() {
+        let frames: Vec<PixelFrame> = PixelFrame::BLACK
+            .crossfade(&PixelFrame::WHITE, 2, Easing::EaseInOutCubic)
+            .collect();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], PixelFrame::BLACK);
+        assert_eq!(frames[1][0], PixelColor::new(128, 128, 128));
+        assert_eq!(frames[2], PixelFrame::WHITE);
+    }
+
+    #[test]
+    fn scanline_reveal_shows_one_more_row_per_frame() {
+        let frames: Vec<PixelFrame> = PixelFrame::RED.scanline_reveal(PixelColor::BLUE).collect();
+        assert_eq!(frames.len(), 8);
+        let first = frames[0].as_rows();
+        assert_eq!(first[0], [PixelColor::RED; 8]);
+        assert!(first[1..].iter().all(|row| *row == [PixelColor::BLUE; 8]));
+        let third = frames[2].as_rows();
+        assert_eq!(third[2], [PixelColor::RED; 8]);
+        assert_eq!(third[3], [PixelColor::BLUE; 8]);
+        assert_eq!(frames[7], PixelFrame::RED);
+    }
+
+    #[test]
+    fn reveal_columns_shows_one_more_column_per_frame() {
+        let mut glyph = PixelFrame::BLACK;
+        for (idx, px) in glyph.0.iter_mut().enumerate() {
+            *px = PixelColor::new(0, (idx % 8) as u8 * 30 + 10, 0);
+        }
+        let bg = PixelColor::BLUE;
+        for &from_left in [true, false].iter() {
+            let frames: Vec<PixelFrame> = glyph.reveal_columns(bg, from_left).collect();
+            assert_eq!(frames.len(), 9);
+            assert_eq!(frames[0], PixelFrame([bg; 64]));
+            assert_eq!(frames[8], glyph);
+            for (shown, frame) in frames.iter().enumerate() {
+                for (col, column) in frame.as_columns().iter().enumerate() {
+                    let revealed = if from_left {
+                        col < shown
+                    } else {
+                        7 - col < shown
+                    };
+                    let expected = if revealed {
+                        glyph.as_columns()[col]
+                    } else {
+                        [bg; 8]
+                    };
+                    assert_eq!(*column, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transition_with_zero_steps_yields_only_to() {
+        let frames: Vec<PixelFrame> = PixelFrame::RED.spiral_to(&PixelFrame::BLUE, 0).collect();
+        assert_eq!(frames, vec![PixelFrame::BLUE]);
+        let frames: Vec<PixelFrame> = PixelFrame::RED
+            .crossfade(&PixelFrame::BLUE, 0, Easing::Linear)
+            .collect();
+        assert_eq!(frames, vec![PixelFrame::BLUE]);
+    }
+}
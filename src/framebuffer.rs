@@ -1,24 +1,940 @@
 //! Framebuffer support for the Sense HAT LED Matrix.
-use super::{error::ScreenError, FrameLine};
-use framebuffer::Framebuffer;
+use super::{error::ScreenError, FrameLine, PixelColor, PixelFrame};
+use framebuffer::{Framebuffer, FramebufferError, VarScreeninfo};
+use memmap::MmapOptions;
+use std::fs::File;
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::os::unix::io::{AsRawFd, FromRawFd, RawFd};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// `ioctl` requests supported by the Sense HAT framebuffer driver (`rpisense-fb`).
+const SENSE_HAT_FB_FBIOGET_GAMMA: libc::c_ulong = 61696;
+const SENSE_HAT_FB_FBIOSET_GAMMA: libc::c_ulong = 61697;
+const SENSE_HAT_FB_FBIORESET_GAMMA: libc::c_ulong = 61698;
+// `ioctl` request that waits for the next vertical blank, `_IOW('F', 0x20, __u32)` in
+// `linux/fb.h`.
+const FBIO_WAITFORVSYNC: libc::c_ulong = 0x4004_4620;
+// Arguments to `SENSE_HAT_FB_FBIORESET_GAMMA`, selecting the driver's gamma table preset.
+const SENSE_HAT_FB_GAMMA_DEFAULT: libc::c_ulong = 0;
+const SENSE_HAT_FB_GAMMA_LOW: libc::c_ulong = 1;
+// Number of frames written over each period of `Screen::breathe`.
+const BREATHE_STEPS: u32 = 32;
+
+/// The default gamma lookup table of the Sense HAT framebuffer driver.
+pub const GAMMA_DEFAULT: [u8; 32] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x02, 0x02, 0x03, 0x03, 0x04, 0x05, 0x06, 0x07,
+    0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0E, 0x0F, 0x11, 0x12, 0x14, 0x15, 0x17, 0x19, 0x1B, 0x1D, 0x1F,
+];
+
+/// The low-light gamma lookup table of the Sense HAT framebuffer driver.
+pub const GAMMA_LOW_LIGHT: [u8; 32] = [
+    0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x02, 0x02,
+    0x03, 0x03, 0x03, 0x04, 0x04, 0x05, 0x05, 0x06, 0x06, 0x07, 0x07, 0x08, 0x08, 0x09, 0x0A, 0x0A,
+];
+
+/// A device, or virtual backend, that renders `FrameLine`s.
+pub trait FrameWriter {
+    /// Render a `FrameLine`.
+    fn write_frame(&mut self, frame: &FrameLine);
+
+    /// Render every `FrameLine` in sequence, one every `delay`.
+    ///
+    /// The frames are paced by a single timing loop, so the time spent writing each frame
+    /// does not accumulate into the delay between frames.
+    fn write_frames<'a, I>(&mut self, frames: I, delay: Duration)
+    where
+        I: IntoIterator<Item = &'a FrameLine>,
+    {
+        let start = Instant::now();
+        let mut deadline = start;
+        for frame in frames {
+            self.write_frame(frame);
+            deadline += delay;
+            let now = Instant::now();
+            if deadline > now {
+                thread::sleep(deadline - now);
+            }
+        }
+    }
+}
+
+impl<W: FrameWriter + ?Sized> FrameWriter for &mut W {
+    fn write_frame(&mut self, frame: &FrameLine) {
+        (**self).write_frame(frame);
+    }
+}
+
+/// A guard that writes an all-black frame to its `FrameWriter` when it is dropped, so that
+/// the screen goes dark when the program exits, or panics.
+///
+/// The guard dereferences to the wrapped writer.
+#[derive(Debug)]
+pub struct ClearOnDrop<W: FrameWriter> {
+    writer: W,
+}
+
+impl<W: FrameWriter> ClearOnDrop<W> {
+    /// Wrap a `FrameWriter`, clearing it when the guard is dropped.
+    pub fn new(writer: W) -> Self {
+        ClearOnDrop { writer }
+    }
+}
+
+impl<W: FrameWriter> Deref for ClearOnDrop<W> {
+    type Target = W;
+
+    fn deref(&self) -> &W {
+        &self.writer
+    }
+}
+
+impl<W: FrameWriter> DerefMut for ClearOnDrop<W> {
+    fn deref_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<W: FrameWriter> Drop for ClearOnDrop<W> {
+    fn drop(&mut self) {
+        self.writer.write_frame(&PixelFrame::BLACK.frame_line());
+    }
+}
+
+/// Geometry, and pixel format, of the framebuffer device.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScreenInfo {
+    /// Visible width, in pixels.
+    pub width: u32,
+    /// Visible height, in pixels.
+    pub height: u32,
+    /// Number of bits used by each pixel.
+    pub bits_per_pixel: u32,
+    /// Length of each line, in bytes.
+    pub line_length: u32,
+}
+
+impl ScreenInfo {
+    /// Check that the framebuffer has the `8x8` geometry of the LED Matrix.
+    pub fn validate(&self) -> Result<(), ScreenError> {
+        if self.width != 8 || self.height != 8 {
+            return Err(ScreenError::UnexpectedGeometry {
+                width: self.width,
+                height: self.height,
+            });
+        }
+        Ok(())
+    }
+}
 
 /// This is the main type for interacting with the LED Matrix Screen.
 #[derive(Debug)]
 pub struct Screen {
     framebuffer: Framebuffer,
+    frame: Option<PixelFrame>,
+    history: FrameHistory,
+    brightness_map: Option<[f32; 64]>,
+    // Whether the current frame is what the framebuffer displays.
+    frame_displayed: bool,
+    frames_written: usize,
+}
+
+// Bounded history of the frames that were displayed before the current one.
+#[derive(Debug, Default, PartialEq)]
+struct FrameHistory {
+    capacity: usize,
+    frames: Vec<PixelFrame>,
+}
+
+impl FrameHistory {
+    // Create an empty history that holds up to `capacity` frames.
+    fn new(capacity: usize) -> Self {
+        FrameHistory {
+            capacity,
+            frames: Vec::with_capacity(capacity),
+        }
+    }
+
+    // Change the capacity, evicting the oldest frames that no longer fit.
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.evict();
+    }
+
+    // Record a frame, evicting the oldest one when the history is full.
+    fn push(&mut self, frame: PixelFrame) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.frames.push(frame);
+        self.evict();
+    }
+
+    // Take the most recently recorded frame.
+    fn pop(&mut self) -> Option<PixelFrame> {
+        self.frames.pop()
+    }
+
+    fn evict(&mut self) {
+        if self.frames.len() > self.capacity {
+            let excess = self.frames.len() - self.capacity;
+            self.frames.drain(..excess);
+        }
+    }
 }
 
 #[cfg(feature = "linux-framebuffer")]
 impl Screen {
-    /// Open the framebuffer to the screen at the given file-system path.
+    /// Open the framebuffer to the screen at the given file-system path, and check that it
+    /// has the `8x8` geometry of the LED Matrix, returning `ScreenError::UnexpectedGeometry`
+    /// otherwise. This catches other framebuffers, such as the HDMI output at `/dev/fb0`.
     pub fn open(path: &str) -> Result<Self, ScreenError> {
+        let screen = Screen::open_unchecked(path)?;
+        screen.info().validate()?;
+        Ok(screen)
+    }
+
+    /// Open the framebuffer to the screen at the given file-system path, without checking
+    /// its geometry.
+    pub fn open_unchecked(path: &str) -> Result<Self, ScreenError> {
         let framebuffer = Framebuffer::new(path)?;
-        Ok(Screen { framebuffer })
+        Ok(Screen::with_framebuffer(framebuffer))
+    }
+
+    /// Create a `Screen` from the file-descriptor of an already open framebuffer device,
+    /// such as one received from a privileged process, and check that it has the `8x8`
+    /// geometry of the LED Matrix, like `Screen::open`.
+    ///
+    /// # Safety
+    /// The `fd` must be an open, readable and writable, framebuffer device. The `Screen`
+    /// takes ownership of it, and closes it when dropped.
+    pub unsafe fn from_raw_fd(fd: RawFd) -> Result<Self, ScreenError> {
+        let screen = Screen::from_raw_fd_unchecked(fd)?;
+        screen.info().validate()?;
+        Ok(screen)
+    }
+
+    /// Create a `Screen` from the file-descriptor of an already open framebuffer device,
+    /// without checking its geometry.
+    ///
+    /// # Safety
+    /// The `fd` must be an open, readable and writable, framebuffer device. The `Screen`
+    /// takes ownership of it, and closes it when dropped.
+    pub unsafe fn from_raw_fd_unchecked(fd: RawFd) -> Result<Self, ScreenError> {
+        let device = File::from_raw_fd(fd);
+        let var_screen_info = Framebuffer::get_var_screeninfo(&device)?;
+        let fix_screen_info = Framebuffer::get_fix_screeninfo(&device)?;
+        let len = fix_screen_info.line_length as usize * var_screen_info.yres_virtual as usize;
+        let frame = MmapOptions::new()
+            .len(len)
+            .map_mut(&device)
+            .map_err(FramebufferError::from)?;
+        Ok(Screen::with_framebuffer(Framebuffer {
+            device,
+            frame,
+            var_screen_info,
+            fix_screen_info,
+        }))
+    }
+
+    fn with_framebuffer(framebuffer: Framebuffer) -> Self {
+        Screen {
+            framebuffer,
+            frame: None,
+            history: FrameHistory::new(0),
+            brightness_map: None,
+            frame_displayed: false,
+            frames_written: 0,
+        }
+    }
+
+    /// Geometry, and pixel format, of the framebuffer device.
+    pub fn info(&self) -> ScreenInfo {
+        let var_info = &self.framebuffer.var_screen_info;
+        ScreenInfo {
+            width: var_info.xres,
+            height: var_info.yres,
+            bits_per_pixel: var_info.bits_per_pixel,
+            line_length: self.framebuffer.fix_screen_info.line_length,
+        }
+    }
+
+    /// The refresh rate of the display, in Hz, computed from the pixel clock and the
+    /// timings reported by the framebuffer driver.
+    ///
+    /// Returns `None` if the driver does not report a pixel clock, which is the case for the
+    /// Sense HAT LED Matrix.
+    pub fn refresh_rate_hz(&self) -> Option<f32> {
+        refresh_rate_hz(&self.framebuffer.var_screen_info)
+    }
+
+    /// Wrap the screen in a `ClearOnDrop` guard, which turns every LED off when dropped.
+    pub fn with_clear_on_drop(self) -> ClearOnDrop<Self> {
+        ClearOnDrop::new(self)
     }
 
     /// Write the contents of a `FrameLine` into the framebuffer. This will
     /// render the frameline on the screen.
     pub fn write_frame(&mut self, frame: &FrameLine) {
-        self.framebuffer.write_frame(&frame.as_bytes());
+        self.frames_written += 1;
+        self.frame_displayed = false;
+        match self.brightness_map {
+            Some(ref map) => {
+                let frame = apply_brightness_map(frame, map);
+                self.framebuffer.write_frame(&frame.as_bytes());
+            }
+            None => self.framebuffer.write_frame(&frame.as_bytes()),
+        }
+    }
+
+    /// Wait for the next vertical blank of the display, to avoid tearing.
+    ///
+    /// Returns `ScreenError::VsyncUnsupported` if the framebuffer driver does not support
+    /// waiting for vsync.
+    pub fn wait_vsync(&self) -> Result<(), ScreenError> {
+        let mut crtc: u32 = 0;
+        let arg = &mut crtc as *mut u32 as libc::c_ulong;
+        match self.ioctl(FBIO_WAITFORVSYNC, arg) {
+            Err(ScreenError::Io(ref err)) if is_unsupported(err) => {
+                Err(ScreenError::VsyncUnsupported)
+            }
+            result => result,
+        }
+    }
+
+    /// Wait for the next vertical blank of the display, and write the contents of a
+    /// `FrameLine` into the framebuffer.
+    pub fn write_frame_synced(&mut self, frame: &FrameLine) -> Result<(), ScreenError> {
+        self.wait_vsync()?;
+        self.write_frame(frame);
+        Ok(())
+    }
+
+    /// Set a per-pixel brightness scale, which is applied with `PixelColor::dim` to every
+    /// frame before it is written, to calibrate the uniformity of the LEDs. Each scale is
+    /// clamped to the `0.0..=1.0` range, and a map of all `1.0` leaves frames untouched.
+    pub fn set_brightness_map(&mut self, map: [f32; 64]) {
+        self.brightness_map = Some(map);
+        self.frame_displayed = false;
+    }
+
+    /// Write a `PixelFrame` into the framebuffer, and keep it as the current frame.
+    ///
+    /// When the history is enabled, with `Screen::set_history_capacity`, the frame that was
+    /// current before is recorded, so that it can be restored with `Screen::undo`. Frames
+    /// written with `Screen::write_frame` are not recorded.
+    ///
+    /// Writing the frame that is already displayed is a no-op. Use `Screen::force_write` to
+    /// write it anyway.
+    pub fn write_pixel_frame(&mut self, frame: &PixelFrame) {
+        let displayed = if self.frame_displayed {
+            self.frame
+        } else {
+            None
+        };
+        if write_if_changed(self, displayed.as_ref(), frame) {
+            self.set_current_frame(*frame);
+        }
+    }
+
+    /// Write a `PixelFrame` into the framebuffer, and keep it as the current frame, like
+    /// `Screen::write_pixel_frame`, even if it is already displayed.
+    pub fn force_write(&mut self, frame: &PixelFrame) {
+        self.write_frame(&frame.frame_line());
+        self.set_current_frame(*frame);
+    }
+
+    /// Fill the screen with `color`, and ramp its brightness up and down, like a slow
+    /// breath, once every `period`, for the given number of `cycles`. Each cycle starts, and
+    /// ends, in black.
+    pub fn breathe(&mut self, color: PixelColor, period: Duration, cycles: usize) {
+        breathe(self, color, period, cycles);
+    }
+
+    /// The number of frames written into the framebuffer since the screen was opened.
+    pub fn frames_written(&self) -> usize {
+        self.frames_written
+    }
+
+    // Keep the frame that was just written as the current frame, and record the previous one.
+    fn set_current_frame(&mut self, frame: PixelFrame) {
+        if let Some(previous) = self.frame.replace(frame) {
+            self.history.push(previous);
+        }
+        self.frame_displayed = true;
+    }
+
+    /// Save a screenshot of the screen as a PNG image at `path`, with every pixel upscaled to
+    /// a `scale x scale` square.
+    ///
+    /// The screenshot is the current frame, if it is what the framebuffer displays, or else
+    /// the contents of the framebuffer. Returns `ScreenError::FramebufferTooSmall` if the
+    /// framebuffer has less than the 128 bytes of a `FrameLine`.
+    #[cfg(feature = "image-support")]
+    pub fn save_png(&self, path: &str, scale: usize) -> Result<(), ScreenError> {
+        let frame = match self.frame {
+            Some(frame) if self.frame_displayed => frame,
+            _ => read_pixel_frame(self.framebuffer.read_frame())?,
+        };
+        std::fs::write(path, encode_png(&frame, scale)?)?;
+        Ok(())
+    }
+
+    /// The last `PixelFrame` written with `Screen::write_pixel_frame`, or restored with
+    /// `Screen::undo`.
+    pub fn current_frame(&self) -> Option<&PixelFrame> {
+        self.frame.as_ref()
+    }
+
+    /// Set how many previous frames are kept for `Screen::undo`. The history is disabled
+    /// by default, with a capacity of `0`. Shrinking the capacity evicts the oldest frames.
+    pub fn set_history_capacity(&mut self, capacity: usize) {
+        self.history.set_capacity(capacity);
+    }
+
+    /// Re-display the frame that was current before the last `Screen::write_pixel_frame`,
+    /// and return it. Returns `None`, leaving the screen untouched, when the history is empty.
+    pub fn undo(&mut self) -> Option<PixelFrame> {
+        let previous = self.history.pop()?;
+        self.write_frame(&previous.frame_line());
+        self.frame = Some(previous);
+        self.frame_displayed = true;
+        Some(previous)
+    }
+
+    /// Read the gamma lookup table used by the hardware, which maps each of the 32 levels of
+    /// the 5-bit LED channels to the brightness that is displayed.
+    pub fn gamma_table(&self) -> Result<[u8; 32], ScreenError> {
+        let mut table = [0u8; 32];
+        self.ioctl(
+            SENSE_HAT_FB_FBIOGET_GAMMA,
+            table.as_mut_ptr() as libc::c_ulong,
+        )?;
+        Ok(table)
+    }
+
+    /// Upload a gamma lookup table to the hardware, which maps each of the 32 levels of the
+    /// 5-bit LED channels to the brightness that is displayed.
+    ///
+    /// Every value in the table must be in the `0..=31` range.
+    pub fn set_gamma_table(&mut self, table: [u8; 32]) -> Result<(), ScreenError> {
+        validate_gamma_table(&table)?;
+        self.ioctl(SENSE_HAT_FB_FBIOSET_GAMMA, table.as_ptr() as libc::c_ulong)
+    }
+
+    /// Restore the hardware's default gamma lookup table.
+    pub fn reset_gamma(&mut self) -> Result<(), ScreenError> {
+        self.ioctl(SENSE_HAT_FB_FBIORESET_GAMMA, SENSE_HAT_FB_GAMMA_DEFAULT)
+    }
+
+    /// Toggle the low-light mode of the hardware, by switching the driver to its low-light
+    /// gamma preset, `GAMMA_LOW_LIGHT`, or back to its default, `GAMMA_DEFAULT`.
+    ///
+    /// This is independent of any software dimming, such as `PixelColor::dim`, which is
+    /// applied to the colors before they are written to the screen.
+    pub fn set_low_light(&mut self, enabled: bool) -> Result<(), ScreenError> {
+        let preset = if enabled {
+            SENSE_HAT_FB_GAMMA_LOW
+        } else {
+            SENSE_HAT_FB_GAMMA_DEFAULT
+        };
+        self.ioctl(SENSE_HAT_FB_FBIORESET_GAMMA, preset)
+    }
+
+    // Issue an `ioctl` request on the framebuffer's file-descriptor.
+    fn ioctl(&self, request: libc::c_ulong, arg: libc::c_ulong) -> Result<(), ScreenError> {
+        let fd = self.framebuffer.device.as_raw_fd();
+        match unsafe { libc::ioctl(fd, request as _, arg) } {
+            -1 => Err(ScreenError::Io(io::Error::last_os_error())),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl FrameWriter for Screen {
+    fn write_frame(&mut self, frame: &FrameLine) {
+        Screen::write_frame(self, frame);
+    }
+}
+
+// Write the `frame` with the `writer`, unless it is the `displayed` frame. Returns `true` if
+// the frame was written.
+fn write_if_changed<W: FrameWriter>(
+    writer: &mut W,
+    displayed: Option<&PixelFrame>,
+    frame: &PixelFrame,
+) -> bool {
+    if displayed == Some(frame) {
+        return false;
+    }
+    writer.write_frame(&frame.frame_line());
+    true
+}
+
+// Write the frames of `Screen::breathe` with the `writer`.
+fn breathe<W: FrameWriter>(writer: &mut W, color: PixelColor, period: Duration, cycles: usize) {
+    let frame = PixelFrame::new(&[color; 64]);
+    let cycle: Vec<FrameLine> = (0..BREATHE_STEPS)
+        .map(|step| {
+            let phase = 2.0 * std::f32::consts::PI * step as f32 / BREATHE_STEPS as f32;
+            frame.dim((1.0 - phase.cos()) / 2.0).frame_line()
+        })
+        .collect();
+    let delay = period / BREATHE_STEPS;
+    writer.write_frames((0..cycles).flat_map(|_| cycle.iter()), delay);
+}
+
+// Read the `PixelFrame` displayed by the raw `bytes` of a framebuffer.
+#[cfg(feature = "image-support")]
+fn read_pixel_frame(bytes: &[u8]) -> Result<PixelFrame, ScreenError> {
+    if bytes.len() < 128 {
+        return Err(ScreenError::FramebufferTooSmall { len: bytes.len() });
+    }
+    let mut line = [0u8; 128];
+    line.copy_from_slice(&bytes[..128]);
+    Ok(FrameLine::from_slice(&line).to_pixel_frame())
+}
+
+// Encode the `frame` as an RGB PNG image, with every pixel upscaled to a `scale x scale`
+// square. A `scale` of `0` is taken as `1`.
+#[cfg(feature = "image-support")]
+fn encode_png(frame: &PixelFrame, scale: usize) -> Result<Vec<u8>, ScreenError> {
+    let scale = scale.max(1);
+    let side = 8 * scale;
+    let mut data = Vec::with_capacity(side * side * 3);
+    for row in frame.as_rows().iter() {
+        let mut line = Vec::with_capacity(side * 3);
+        for px in row.iter() {
+            for _ in 0..scale {
+                line.extend_from_slice(&[px.red, px.green, px.blue]);
+            }
+        }
+        for _ in 0..scale {
+            data.extend_from_slice(&line);
+        }
+    }
+    let mut png = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut png, side as u32, side as u32);
+        encoder.set_color(png::ColorType::RGB);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&data)?;
+    }
+    Ok(png)
+}
+
+// Check if an `ioctl` failed because the driver does not implement the request.
+fn is_unsupported(err: &io::Error) -> bool {
+    match err.raw_os_error() {
+        Some(code) => code == libc::ENOTTY || code == libc::EINVAL || code == libc::EOPNOTSUPP,
+        None => false,
+    }
+}
+
+// Scale the color of every pixel in the frame by its value in the brightness map.
+fn apply_brightness_map(frame: &FrameLine, map: &[f32; 64]) -> FrameLine {
+    let mut pixels = frame.to_pixel_frame();
+    for (idx, &scale) in map.iter().enumerate() {
+        pixels[idx] = pixels[idx].dim(scale);
+    }
+    pixels.frame_line()
+}
+
+// Compute the refresh rate from the pixel clock, in picoseconds, and the total number of
+// pixels in each frame, including margins and sync lengths.
+fn refresh_rate_hz(var_info: &VarScreeninfo) -> Option<f32> {
+    let h_total = var_info.xres + var_info.left_margin + var_info.right_margin + var_info.hsync_len;
+    let v_total =
+        var_info.yres + var_info.upper_margin + var_info.lower_margin + var_info.vsync_len;
+    let picos_per_frame = f64::from(var_info.pixclock) * f64::from(h_total) * f64::from(v_total);
+    if picos_per_frame == 0.0 {
+        return None;
+    }
+    Some((1e12 / picos_per_frame) as f32)
+}
+
+// Check that every value in the gamma table is in the `0..=31` range.
+fn validate_gamma_table(table: &[u8; 32]) -> Result<(), ScreenError> {
+    if table.iter().any(|&level| level > 31) {
+        return Err(ScreenError::InvalidGammaTable);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PixelColor;
+
+    // Virtual backend that records every frame written to it.
+    #[derive(Debug, Default)]
+    struct VirtualScreen {
+        written: Vec<FrameLine>,
+    }
+
+    impl FrameWriter for VirtualScreen {
+        fn write_frame(&mut self, frame: &FrameLine) {
+            self.written.push(*frame);
+        }
+    }
+
+    #[test]
+    fn frame_writer_writes_frames_in_sequence_with_delay() {
+        let frames = [PixelFrame::RED.frame_line(), PixelFrame::BLUE.frame_line()];
+        let mut screen = VirtualScreen::default();
+        let start = Instant::now();
+        screen.write_frames(&frames, Duration::from_millis(10));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        assert_eq!(screen.written, frames.to_vec());
+    }
+
+    #[test]
+    fn breathing_peaks_in_the_middle_of_each_cycle() {
+        let mut screen = VirtualScreen::default();
+        breathe(&mut screen, PixelColor::WHITE, Duration::from_millis(32), 2);
+        let steps = BREATHE_STEPS as usize;
+        assert_eq!(screen.written.len(), steps * 2);
+        let brightness: Vec<u8> = screen
+            .written
+            .iter()
+            .map(|line| line.to_pixel_frame()[0].red)
+            .collect();
+        assert_eq!(brightness[0], 0);
+        assert_eq!(brightness[steps / 2], *brightness.iter().max().unwrap());
+        assert!(brightness[steps / 2] > brightness[steps / 4]);
+        assert!(brightness[1..=steps / 2].windows(2).all(|w| w[0] <= w[1]));
+        assert!(brightness[steps / 2..steps]
+            .windows(2)
+            .all(|w| w[0] >= w[1]));
+        assert_eq!(brightness[..steps], brightness[steps..]);
+    }
+
+    #[test]
+    fn clear_on_drop_writes_a_black_frame_when_dropped() {
+        let mut screen = VirtualScreen::default();
+        {
+            let mut guard = ClearOnDrop::new(&mut screen);
+            guard.write_frame(&PixelFrame::RED.frame_line());
+        }
+        assert_eq!(
+            screen.written,
+            vec![PixelFrame::RED.frame_line(), PixelFrame::BLACK.frame_line()]
+        );
+    }
+
+    #[test]
+    fn brightness_map_of_all_ones_is_a_no_op() {
+        let frame = PixelFrame::new(&[PixelColor::new(0xF8, 0x80, 0x18); 64]).frame_line();
+        assert_eq!(apply_brightness_map(&frame, &[1.0; 64]), frame);
+    }
+
+    #[test]
+    fn brightness_map_with_zero_scale_blacks_out_the_pixel() {
+        let mut map = [1.0; 64];
+        map[0] = 0.0;
+        let frame = apply_brightness_map(&PixelFrame::WHITE.frame_line(), &map).to_pixel_frame();
+        assert_eq!(frame[0], PixelColor::BLACK);
+        assert_eq!(frame[1], PixelFrame::WHITE.frame_line().to_pixel_frame()[1]);
+    }
+
+    #[test]
+    fn frame_history_grows_up_to_its_capacity() {
+        let mut history = FrameHistory::new(3);
+        history.push(PixelFrame::RED);
+        history.push(PixelFrame::GREEN);
+        assert_eq!(history.frames, vec![PixelFrame::RED, PixelFrame::GREEN]);
+    }
+
+    #[test]
+    fn frame_history_evicts_the_oldest_frames_when_full() {
+        let mut history = FrameHistory::new(2);
+        history.push(PixelFrame::RED);
+        history.push(PixelFrame::GREEN);
+        history.push(PixelFrame::BLUE);
+        assert_eq!(history.frames, vec![PixelFrame::GREEN, PixelFrame::BLUE]);
+        history.set_capacity(1);
+        assert_eq!(history.frames, vec![PixelFrame::BLUE]);
+    }
+
+    #[test]
+    fn frame_history_with_zero_capacity_records_nothing() {
+        let mut history = FrameHistory::new(0);
+        history.push(PixelFrame::RED);
+        assert_eq!(history.pop(), None);
+    }
+
+    #[test]
+    fn frame_history_pops_the_prior_frame() {
+        let mut history = FrameHistory::new(4);
+        history.push(PixelFrame::RED);
+        history.push(PixelFrame::GREEN);
+        assert_eq!(history.pop(), Some(PixelFrame::GREEN));
+        assert_eq!(history.pop(), Some(PixelFrame::RED));
+        assert_eq!(history.pop(), None);
+    }
+
+    #[test]
+    #[ignore = "requires a Sense HAT at /dev/fb1"]
+    fn screen_undo_restores_the_prior_frame() {
+        let mut screen = Screen::open("/dev/fb1").unwrap();
+        screen.set_history_capacity(4);
+        screen.write_pixel_frame(&PixelFrame::RED);
+        screen.write_pixel_frame(&PixelFrame::GREEN);
+        assert_eq!(screen.undo(), Some(PixelFrame::RED));
+        assert_eq!(screen.current_frame(), Some(&PixelFrame::RED));
+        assert_eq!(screen.undo(), None);
+    }
+
+    #[cfg(feature = "image-support")]
+    #[test]
+    fn png_screenshot_is_upscaled_by_the_scale() {
+        let mut frame = PixelFrame::BLUE;
+        frame[9] = PixelColor::RED;
+        let png = encode_png(&frame, 3).unwrap();
+        let (info, mut reader) = png::Decoder::new(&png[..]).read_info().unwrap();
+        assert_eq!((info.width, info.height), (24, 24));
+        let mut data = vec![0; info.buffer_size()];
+        reader.next_frame(&mut data).unwrap();
+        let rgb = |x: usize, y: usize| {
+            let at = (y * 24 + x) * 3;
+            PixelColor::new(data[at], data[at + 1], data[at + 2])
+        };
+        assert_eq!(rgb(0, 0), PixelColor::BLUE);
+        assert_eq!(rgb(3, 3), PixelColor::RED);
+        assert_eq!(rgb(5, 5), PixelColor::RED);
+        assert_eq!(rgb(6, 5), PixelColor::BLUE);
+    }
+
+    #[cfg(feature = "image-support")]
+    #[test]
+    fn framebuffer_is_read_back_as_a_pixel_frame() {
+        let line = PixelFrame::RED.frame_line();
+        let bytes = line.as_bytes();
+        assert_eq!(read_pixel_frame(&bytes).unwrap(), line.to_pixel_frame());
+        match read_pixel_frame(&bytes[..64]) {
+            Err(ScreenError::FramebufferTooSmall { len }) => assert_eq!(len, 64),
+            other => panic!("expected a short framebuffer error, got {:?}", other),
+        }
+    }
+
+    #[cfg(feature = "image-support")]
+    #[test]
+    #[ignore = "requires a Sense HAT at /dev/fb1"]
+    fn screen_saves_a_png_screenshot() {
+        let mut screen = Screen::open("/dev/fb1").unwrap();
+        screen.write_pixel_frame(&PixelFrame::GREEN);
+        let path = std::env::temp_dir().join("sensehat-screenshot.png");
+        let path = path.to_str().unwrap();
+        screen.save_png(path, 4).unwrap();
+        assert_eq!(
+            std::fs::read(path).unwrap(),
+            encode_png(&PixelFrame::GREEN, 4).unwrap()
+        );
+        // A raw write replaces the current frame on the screen.
+        screen.write_frame(&PixelFrame::RED.frame_line());
+        screen.save_png(path, 4).unwrap();
+        let displayed = PixelFrame::RED.frame_line().to_pixel_frame();
+        assert_eq!(
+            std::fs::read(path).unwrap(),
+            encode_png(&displayed, 4).unwrap()
+        );
+    }
+
+    #[test]
+    fn waitforvsync_request_matches_the_kernel_encoding() {
+        // `_IOW('F', 0x20, __u32)`: write direction, 4-byte argument, type 'F', number 0x20.
+        let request = 1 << 30 | 4 << 16 | libc::c_ulong::from(b'F') << 8 | 0x20;
+        assert_eq!(FBIO_WAITFORVSYNC, request);
+    }
+
+    #[test]
+    fn unimplemented_ioctls_are_unsupported() {
+        assert!(is_unsupported(&io::Error::from_raw_os_error(libc::ENOTTY)));
+        assert!(!is_unsupported(&io::Error::from_raw_os_error(libc::EBADF)));
+    }
+
+    #[test]
+    #[ignore = "requires a Sense HAT at /dev/fb1"]
+    fn screen_waits_for_vsync_or_reports_it_unsupported() {
+        let mut screen = Screen::open("/dev/fb1").unwrap();
+        match screen.write_frame_synced(&PixelFrame::BLACK.frame_line()) {
+            Ok(()) | Err(ScreenError::VsyncUnsupported) => {}
+            Err(err) => panic!("unexpected error: {:?}", err),
+        }
+    }
+
+    #[test]
+    fn screen_info_with_8x8_geometry_is_valid() {
+        let info = ScreenInfo {
+            width: 8,
+            height: 8,
+            bits_per_pixel: 16,
+            line_length: 16,
+        };
+        assert!(info.validate().is_ok());
+    }
+
+    #[test]
+    fn screen_info_with_other_geometry_is_invalid() {
+        let info = ScreenInfo {
+            width: 1920,
+            height: 1080,
+            bits_per_pixel: 32,
+            line_length: 7680,
+        };
+        match info.validate() {
+            Err(ScreenError::UnexpectedGeometry { width, height }) => {
+                assert_eq!((width, height), (1920, 1080));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn screen_info_with_non_square_geometry_is_invalid() {
+        let info = ScreenInfo {
+            width: 8,
+            height: 16,
+            bits_per_pixel: 16,
+            line_length: 16,
+        };
+        assert!(info.validate().is_err());
+    }
+
+    #[test]
+    #[ignore = "requires a Sense HAT at /dev/fb1"]
+    fn screen_is_created_from_an_open_file_descriptor() {
+        use std::os::unix::io::IntoRawFd;
+        let device = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open("/dev/fb1")
+            .unwrap();
+        let mut screen = unsafe { Screen::from_raw_fd(device.into_raw_fd()) }.unwrap();
+        screen.write_frame(&PixelFrame::BLACK.frame_line());
+    }
+
+    #[test]
+    #[ignore = "requires a Sense HAT at /dev/fb1"]
+    fn screen_info_reports_the_led_matrix_geometry() {
+        let screen = Screen::open("/dev/fb1").unwrap();
+        let info = screen.info();
+        assert_eq!((info.width, info.height), (8, 8));
+        assert_eq!(info.bits_per_pixel, 16);
+        assert_eq!(info.line_length, 16);
+    }
+
+    // A `VarScreeninfo` with the standard VESA `640x480` timings, at 60Hz.
+    fn vesa_var_screen_info() -> VarScreeninfo {
+        // `VarScreeninfo` is a plain C struct of integers, so all zeroes is a valid value.
+        let mut var_info: VarScreeninfo = unsafe { std::mem::zeroed() };
+        var_info.xres = 640;
+        var_info.yres = 480;
+        var_info.pixclock = 39_722;
+        var_info.left_margin = 48;
+        var_info.right_margin = 16;
+        var_info.hsync_len = 96;
+        var_info.upper_margin = 33;
+        var_info.lower_margin = 10;
+        var_info.vsync_len = 2;
+        var_info
+    }
+
+    #[test]
+    fn refresh_rate_is_computed_from_the_pixel_clock_and_timings() {
+        let hz = refresh_rate_hz(&vesa_var_screen_info()).unwrap();
+        assert!((hz - 59.94).abs() < 0.01, "{}", hz);
+    }
+
+    #[test]
+    fn refresh_rate_is_unavailable_without_a_pixel_clock() {
+        let mut var_info = vesa_var_screen_info();
+        var_info.pixclock = 0;
+        assert_eq!(refresh_rate_hz(&var_info), None);
+    }
+
+    #[test]
+    fn writing_the_displayed_frame_again_is_skipped() {
+        let mut screen = VirtualScreen::default();
+        assert!(write_if_changed(&mut screen, None, &PixelFrame::RED));
+        let displayed = PixelFrame::RED;
+        assert!(!write_if_changed(
+            &mut screen,
+            Some(&displayed),
+            &PixelFrame::RED
+        ));
+        assert_eq!(screen.written, vec![PixelFrame::RED.frame_line()]);
+        assert!(write_if_changed(
+            &mut screen,
+            Some(&displayed),
+            &PixelFrame::BLUE
+        ));
+        assert_eq!(screen.written.len(), 2);
+    }
+
+    #[test]
+    #[ignore = "requires a Sense HAT at /dev/fb1"]
+    fn screen_counts_the_frames_written() {
+        let mut screen = Screen::open("/dev/fb1").unwrap();
+        screen.write_pixel_frame(&PixelFrame::RED);
+        screen.write_pixel_frame(&PixelFrame::RED);
+        assert_eq!(screen.frames_written(), 1);
+        screen.force_write(&PixelFrame::RED);
+        assert_eq!(screen.frames_written(), 2);
+        screen.write_frame(&PixelFrame::BLUE.frame_line());
+        screen.write_pixel_frame(&PixelFrame::RED);
+        assert_eq!(screen.frames_written(), 4);
+    }
+
+    #[test]
+    fn gamma_tables_with_5_bit_levels_are_valid() {
+        let mut table = [0u8; 32];
+        for (idx, level) in table.iter_mut().enumerate() {
+            *level = idx as u8;
+        }
+        assert!(validate_gamma_table(&table).is_ok());
+    }
+
+    #[test]
+    fn gamma_tables_with_levels_above_31_are_invalid() {
+        let mut table = [0u8; 32];
+        table[31] = 32;
+        assert!(validate_gamma_table(&table).is_err());
+    }
+
+    #[test]
+    fn gamma_presets_are_valid_non_decreasing_tables() {
+        for table in &[GAMMA_DEFAULT, GAMMA_LOW_LIGHT] {
+            assert!(validate_gamma_table(table).is_ok());
+            assert!(table.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+        assert_eq!(GAMMA_DEFAULT[31], 31);
+        assert!(GAMMA_LOW_LIGHT[31] < GAMMA_DEFAULT[31]);
+    }
+
+    #[test]
+    #[ignore = "requires a Sense HAT at /dev/fb1"]
+    fn screen_toggles_low_light_mode() {
+        let mut screen = Screen::open("/dev/fb1").unwrap();
+        screen.set_low_light(true).unwrap();
+        assert_eq!(screen.gamma_table().unwrap(), GAMMA_LOW_LIGHT);
+        screen.set_low_light(false).unwrap();
+        assert_eq!(screen.gamma_table().unwrap(), GAMMA_DEFAULT);
+    }
+
+    #[test]
+    #[ignore = "requires a Sense HAT at /dev/fb1"]
+    fn screen_uploads_and_resets_the_gamma_table() {
+        let mut screen = Screen::open("/dev/fb1").unwrap();
+        let default_table = screen.gamma_table().unwrap();
+        let linear_table = [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31,
+        ];
+        screen.set_gamma_table(linear_table).unwrap();
+        assert_eq!(screen.gamma_table().unwrap(), linear_table);
+        screen.reset_gamma().unwrap();
+        assert_eq!(screen.gamma_table().unwrap(), default_table);
     }
 }
@@ -69,6 +69,16 @@ extern crate font8x8;
 extern crate lazy_static;
 #[cfg(feature = "linux-framebuffer")]
 pub extern crate framebuffer;
+#[cfg(feature = "linux-framebuffer")]
+extern crate libc;
+#[cfg(feature = "linux-framebuffer")]
+extern crate memmap;
+#[cfg(feature = "ndarray-support")]
+extern crate ndarray;
+#[cfg(feature = "image-support")]
+extern crate png;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 #[cfg(feature = "serde-support")]
 extern crate serde;
 #[cfg(feature = "serde-support")]
@@ -81,22 +91,35 @@ pub mod color;
 pub mod frame;
 // Screen errors
 pub mod error;
+// Easing functions for animations
+pub mod easing;
 // 8x8 fonts
 #[cfg(feature = "fonts")]
 pub mod fonts;
+// Conversions to and from ndarray arrays
+#[cfg(feature = "ndarray-support")]
+pub mod ndarray_interop;
 #[cfg(feature = "linux-framebuffer")]
 #[path = "framebuffer.rs"]
 pub mod screen;
 // Scrolls for collections of PixelFrames
 #[cfg(feature = "scroll")]
 pub mod scroll;
+// Temporal smoothing of PixelFrames
+pub mod smooth;
+// Sprites drawn over PixelFrames
+#[cfg(feature = "sprite")]
+pub mod sprite;
 
 // Re-exports
-pub use self::color::{BackgroundColor, PixelColor, StrokeColor};
+pub use self::color::{BackgroundColor, PixelColor, Rgba, StrokeColor};
+
+pub use self::easing::Easing;
 
 #[cfg(feature = "fonts")]
 pub use self::fonts::{
-    font_to_frame, font_to_pixel_frame, FontCollection, FontString, FONT_COLLECTION, FONT_HASHMAP,
+    font_to_frame, font_to_mono_bits, font_to_pixel_frame, FontCollection, FontString,
+    FONT_COLLECTION, FONT_HASHMAP,
 };
 
 #[cfg(feature = "clip")]
@@ -105,13 +128,101 @@ pub use self::frame::clip::Clip;
 #[cfg(any(feature = "offset", feature = "clip"))]
 pub use self::frame::Offset;
 
+#[cfg(feature = "offset")]
+pub use self::frame::offset::Axis;
+
 #[cfg(feature = "rotate")]
 pub use self::frame::rotate::Rotate;
 
-pub use self::frame::{FrameLine, PixelFrame};
+pub use self::frame::{
+    rate::{Throttle, ThrottledFrames},
+    transition::WipeDirection,
+    BlendMode, FrameCanvas, FrameLine, Origin, PixelFrame, Point, Rect, RgbaFrame,
+};
+
+pub use self::smooth::FrameSmoother;
 
 #[cfg(feature = "linux-framebuffer")]
-pub use self::screen::Screen;
+pub use self::screen::{ClearOnDrop, FrameWriter, Screen, ScreenInfo};
 
 #[cfg(feature = "scroll")]
 pub use self::scroll::Scroll;
+
+#[cfg(feature = "sprite")]
+pub use self::sprite::{Scene, Sprite};
+
+/// The optional features that this crate was compiled with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capabilities {
+    /// `base64`: encodes frames as base64 strings.
+    pub base64: bool,
+    /// `big-endian`: encodes RGB565 colors as big-endian bytes.
+    pub big_endian: bool,
+    /// `clip`: clips two frames onto a single frame.
+    pub clip: bool,
+    /// `fonts`: renders text with 8x8 fonts.
+    pub fonts: bool,
+    /// `image-support`: saves screenshots as PNG images.
+    pub image: bool,
+    /// `linux-framebuffer`: writes frames to the LED matrix.
+    pub linux_framebuffer: bool,
+    /// `ndarray-support`: converts frames to and from `ndarray` arrays.
+    pub ndarray: bool,
+    /// `offset`: offsets frames in any direction.
+    pub offset: bool,
+    /// `rayon`: computes the frames of a scroll in parallel.
+    pub rayon: bool,
+    /// `rotate`: rotates frames by 90-degree steps.
+    pub rotate: bool,
+    /// `scroll`: scrolls a sequence of frames.
+    pub scroll: bool,
+    /// `serde-support`: serializes colors and fonts with `serde`.
+    pub serde: bool,
+    /// `sprite`: draws small bitmaps, with transparency, over frames.
+    pub sprite: bool,
+}
+
+/// Returns the optional features that this crate was compiled with.
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        base64: cfg!(feature = "base64"),
+        big_endian: cfg!(feature = "big-endian"),
+        clip: cfg!(feature = "clip"),
+        fonts: cfg!(feature = "fonts"),
+        image: cfg!(feature = "image-support"),
+        linux_framebuffer: cfg!(feature = "linux-framebuffer"),
+        ndarray: cfg!(feature = "ndarray-support"),
+        offset: cfg!(feature = "offset"),
+        rayon: cfg!(feature = "rayon"),
+        rotate: cfg!(feature = "rotate"),
+        scroll: cfg!(feature = "scroll"),
+        serde: cfg!(feature = "serde-support"),
+        sprite: cfg!(feature = "sprite"),
+    }
+}
+
+#[cfg(all(test, feature = "basic", feature = "linux-framebuffer"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capabilities_include_the_default_features() {
+        let caps = capabilities();
+        assert!(caps.clip);
+        assert!(caps.fonts);
+        assert!(caps.linux_framebuffer);
+        assert!(caps.offset);
+        assert!(caps.rotate);
+        assert!(caps.scroll);
+        assert!(caps.serde);
+        assert!(caps.sprite);
+    }
+
+    #[test]
+    fn capabilities_match_the_optional_dependency_features() {
+        let caps = capabilities();
+        assert_eq!(caps.image, cfg!(feature = "image-support"));
+        assert_eq!(caps.ndarray, cfg!(feature = "ndarray-support"));
+        assert_eq!(caps.rayon, cfg!(feature = "rayon"));
+    }
+}
@@ -0,0 +1,55 @@
+//! Conversions between `PixelFrame`s and `ndarray` arrays
+use super::error::ScreenError;
+use super::{PixelColor, PixelFrame};
+use ndarray::Array2;
+
+/// Methods enabled by the `ndarray-support` feature.
+impl PixelFrame {
+    /// Returns an `8x8` array of the pixels, indexed by `[row, column]`.
+    pub fn to_ndarray(&self) -> Array2<PixelColor> {
+        Array2::from_shape_fn((8, 8), |(row, col)| self[row * 8 + col])
+    }
+
+    /// Create a new `PixelFrame` from an array of pixels indexed by `[row, column]`.
+    ///
+    /// Returns `ScreenError::InvalidArrayShape` if the array is not `8x8`.
+    pub fn from_ndarray(array: &Array2<PixelColor>) -> Result<PixelFrame, ScreenError> {
+        let (rows, columns) = array.dim();
+        if (rows, columns) != (8, 8) {
+            return Err(ScreenError::InvalidArrayShape { rows, columns });
+        }
+        let mut frame = PixelFrame::default();
+        for ((row, col), &color) in array.indexed_iter() {
+            frame[row * 8 + col] = color;
+        }
+        Ok(frame)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixel_frame_round_trips_through_ndarray() {
+        let mut frame = PixelFrame::BLUE;
+        frame[1] = PixelColor::RED;
+        frame[8] = PixelColor::GREEN;
+        let array = frame.to_ndarray();
+        assert_eq!(array.dim(), (8, 8));
+        assert_eq!(array[[0, 1]], PixelColor::RED);
+        assert_eq!(array[[1, 0]], PixelColor::GREEN);
+        assert_eq!(PixelFrame::from_ndarray(&array).unwrap(), frame);
+    }
+
+    #[test]
+    fn pixel_frame_from_ndarray_needs_8x8_pixels() {
+        let array = Array2::from_elem((4, 8), PixelColor::RED);
+        match PixelFrame::from_ndarray(&array) {
+            Err(ScreenError::InvalidArrayShape { rows, columns }) => {
+                assert_eq!((rows, columns), (4, 8))
+            }
+            _ => panic!("expected an invalid shape"),
+        }
+    }
+}
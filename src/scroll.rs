@@ -1,9 +1,15 @@
 //! Scrolling for pixel frames on the LED Matrix.
-use super::{Clip, Offset, PixelFrame};
+use super::{Clip, Easing, FrameLine, Offset, PixelFrame};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use std::ops::Index;
+use std::time::Duration;
+
+// How long each transition frame of `Scroll::slideshow` is shown.
+const SLIDESHOW_TRANSITION_DELAY: Duration = Duration::from_millis(50);
 
 /// A sequence of frames
-#[derive(Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum FrameDirection {
     RightToLeft,
     LeftToRight,
@@ -11,6 +17,18 @@ pub enum FrameDirection {
     TopToBottom,
 }
 
+impl FrameDirection {
+    // Returns the `Offset` constructor that moves frames in this direction.
+    fn offset(&self) -> fn(u8) -> Offset {
+        match *self {
+            FrameDirection::RightToLeft => Offset::left,
+            FrameDirection::LeftToRight => Offset::right,
+            FrameDirection::TopToBottom => Offset::bottom,
+            FrameDirection::BottomToTop => Offset::top,
+        }
+    }
+}
+
 /// A sequence of frames to be scrolled on the LED Matrix.
 #[derive(Debug, PartialEq)]
 pub struct FrameSequence {
@@ -36,6 +54,34 @@ impl FrameSequence {
         self.clips.len() * 8
     }
 
+    /// Returns the frame sequence moving in the opposite direction, with the same clips, and
+    /// at the same position.
+    pub fn reversed_direction(self) -> FrameSequence {
+        let direction = match self.direction {
+            FrameDirection::RightToLeft => FrameDirection::LeftToRight,
+            FrameDirection::LeftToRight => FrameDirection::RightToLeft,
+            FrameDirection::TopToBottom => FrameDirection::BottomToTop,
+            FrameDirection::BottomToTop => FrameDirection::TopToBottom,
+        };
+        FrameSequence { direction, ..self }
+    }
+
+    /// Returns an iterator over every `step`-th position of the frame sequence, for faster
+    /// motion, always ending with the final frame. A `step` of `1` yields every position.
+    ///
+    /// # Panics
+    /// If `step` is `0`.
+    pub fn step_by_pixels(self, step: u8) -> impl Iterator<Item = PixelFrame> {
+        assert!(step > 0);
+        let (start, last) = (self.position, self.positions());
+        self.enumerate()
+            .filter(move |&(idx, _)| {
+                let position = start + idx;
+                position % usize::from(step) == 0 || position == last
+            })
+            .map(|(_, frame)| frame)
+    }
+
     // Returns the offset depending on the internal FrameDirection.
     fn offset(&self, off: u8) -> Offset {
         match self.direction {
@@ -83,6 +129,13 @@ impl Scroll {
         Scroll(frames.to_vec())
     }
 
+    // Create a new scroll that takes ownership of the `frames`, which must be at least 2.
+    #[cfg(feature = "fonts")]
+    pub(crate) fn from_vec(frames: Vec<PixelFrame>) -> Self {
+        debug_assert!(frames.len() > 1);
+        Scroll(frames)
+    }
+
     /// Returns `&[PixelFrame]` with the pixel frames that constitute this scroll.
     pub fn frames(&self) -> &[PixelFrame] {
         self.0.as_slice()
@@ -100,6 +153,64 @@ impl Scroll {
         clips
     }
 
+    /// Returns a new `Scroll` with the pixel frames of `other` after the pixel frames of this
+    /// scroll, joined by a clip from the last frame of this scroll to the first of `other`.
+    pub fn chain(&self, other: &Scroll) -> Scroll {
+        let mut frames = self.0.clone();
+        frames.extend_from_slice(&other.0);
+        Scroll(frames)
+    }
+
+    /// Returns the rendered frames of the `FrameSequence` that moves in the given
+    /// `direction`, dropping every frame that is identical to the one before it, so that
+    /// stretches where nothing visible moves are not played frame by frame.
+    ///
+    /// The frames are ready to be played one after another. When nothing visible moves at
+    /// all, there is a single frame.
+    pub fn optimize(&self, direction: FrameDirection) -> Vec<PixelFrame> {
+        let mut frames = self.render_all(direction);
+        frames.dedup();
+        frames
+    }
+
+    /// Returns the frames of a slideshow, each paired with how long it is shown. Every pixel
+    /// frame of the scroll is held for `hold`, and followed by `transition_steps` frames,
+    /// shown for 50 milliseconds each, that scroll it out to the left as the next one comes
+    /// in.
+    pub fn slideshow(
+        &self,
+        hold: Duration,
+        transition_steps: usize,
+    ) -> Vec<(PixelFrame, Duration)> {
+        let mut slides = Vec::with_capacity(self.0.len() * (transition_steps + 1));
+        for clip in self.clips() {
+            slides.push((clip.offset(Offset::left(0)), hold));
+            for step in 1..=transition_steps {
+                let offset = (8 * step / (transition_steps + 1)).max(1).min(7);
+                slides.push((
+                    clip.offset(Offset::left(offset as u8)),
+                    SLIDESHOW_TRANSITION_DELAY,
+                ));
+            }
+        }
+        slides.push((self.0[self.0.len() - 1], hold));
+        slides
+    }
+
+    /// Returns `true` if the first and last pixel frames are equal, so that the scroll loops
+    /// without a visible jump.
+    pub fn is_seamless(&self) -> bool {
+        self.0.first() == self.0.last()
+    }
+
+    /// Append the first pixel frame at the end of the scroll, unless it is already seamless.
+    pub fn make_seamless(&mut self) {
+        if !self.is_seamless() {
+            let first = self.0[0];
+            self.0.push(first);
+        }
+    }
+
     /// Reverse the order of the inner pixel frames.
     pub fn reverse(&mut self) {
         self.0.reverse();
@@ -115,6 +226,44 @@ impl Scroll {
         self.0.is_empty()
     }
 
+    /// Returns every `PixelFrame` of the `FrameSequence` that moves in the given `direction`,
+    /// without writing them to the screen.
+    pub fn render_all(&self, direction: FrameDirection) -> Vec<PixelFrame> {
+        FrameSequence::new(self, direction).collect()
+    }
+
+    /// Returns the `FrameLine`s of every frame of the `FrameSequence` that moves in the given
+    /// `direction`, ready to be written to the screen.
+    pub fn to_frame_lines(&self, direction: FrameDirection) -> Vec<FrameLine> {
+        let offset = direction.offset();
+        let mut lines = Vec::with_capacity(self.0.len() * 8 - 7);
+        for clip in self.clips() {
+            // The last line of a clip is the first line of the next one.
+            lines.pop();
+            lines.extend(clip.frame_lines(offset));
+        }
+        lines
+    }
+
+    /// Like `Scroll::to_frame_lines`, computing the frames in parallel with `rayon`. The
+    /// `FrameLine`s are in the same order.
+    #[cfg(feature = "rayon")]
+    pub fn par_to_frame_lines(&self, direction: FrameDirection) -> Vec<FrameLine> {
+        let offset = direction.offset();
+        let clips = self.clips();
+        let positions = clips.len() * 8;
+        (0..=positions)
+            .into_par_iter()
+            .map(|position| {
+                let (clip, at) = match position {
+                    n if n == positions => (&clips[clips.len() - 1], 8),
+                    n => (&clips[n / 8], n % 8),
+                };
+                clip.offset(offset(at as u8)).frame_line()
+            })
+            .collect()
+    }
+
     /// Returns a `FrameSequence` iterator that moves the frames from the right to the left.
     pub fn right_to_left(&self) -> FrameSequence {
         FrameSequence::new(self, FrameDirection::RightToLeft)
@@ -136,6 +285,20 @@ impl Scroll {
     }
 }
 
+/// Returns `steps + 1` frames that crossfade from the last frame of the sequence of `a`, to
+/// the first frame of the sequence of `b`, both moving in the given `direction`.
+pub fn crossfade_scrolls(
+    a: &Scroll,
+    b: &Scroll,
+    direction: FrameDirection,
+    steps: usize,
+) -> Vec<PixelFrame> {
+    let offset = direction.offset();
+    let from = a[a.len() - 2].clip(&a[a.len() - 1], offset(8));
+    let to = b[0].clip(&b[1], offset(0));
+    from.crossfade(&to, steps, Easing::Linear).collect()
+}
+
 impl Index<usize> for Scroll {
     type Output = PixelFrame;
 
@@ -193,6 +356,163 @@ mod tests {
         assert_eq!(scroll.clips(), expected_clips);
     }
 
+    #[test]
+    fn scroll_is_chained_with_another_scroll() {
+        let scroll = Scroll::new(SCROLL_ONE).chain(&Scroll::new(SCROLL_TWO));
+        assert_eq!(scroll.len(), 5);
+        assert_eq!(scroll.frames(), &[BLK, RED, BLK, RED, YLW]);
+        let clips = scroll.clips();
+        assert_eq!(clips.len(), 4);
+        assert_eq!(clips[1], RED.build_clip(&BLK));
+    }
+
+    #[test]
+    fn frame_sequence_with_reversed_direction_matches_the_opposite_sequence() {
+        let scroll = Scroll::new(SCROLL_TWO);
+        let reversed: Vec<PixelFrame> = scroll.right_to_left().reversed_direction().collect();
+        assert_eq!(
+            reversed,
+            scroll.left_to_right().collect::<Vec<PixelFrame>>()
+        );
+        let reversed: Vec<PixelFrame> = scroll.bottom_to_top().reversed_direction().collect();
+        assert_eq!(
+            reversed,
+            scroll.top_to_bottom().collect::<Vec<PixelFrame>>()
+        );
+        assert_eq!(
+            scroll.left_to_right().reversed_direction(),
+            scroll.right_to_left()
+        );
+    }
+
+    #[test]
+    fn scroll_renders_all_the_frames_of_its_sequence() {
+        let scroll = Scroll::new(&[RED, BLK, YLW]);
+        let frames = scroll.render_all(FrameDirection::LeftToRight);
+        assert_eq!(frames.len(), scroll.left_to_right().positions() + 1);
+        assert_eq!(frames[0], RED);
+        assert_eq!(frames[frames.len() - 1], YLW);
+        assert_eq!(frames, scroll.left_to_right().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn scroll_is_converted_to_the_frame_lines_of_its_sequence() {
+        let scroll = Scroll::new(SCROLL_TWO);
+        let lines = scroll.to_frame_lines(FrameDirection::RightToLeft);
+        assert_eq!(lines.len(), scroll.right_to_left().positions() + 1);
+        assert_eq!(lines[0], scroll[0].frame_line());
+        assert_eq!(lines[16], scroll[2].frame_line());
+        let expected: Vec<FrameLine> = scroll
+            .top_to_bottom()
+            .map(|frame| frame.frame_line())
+            .collect();
+        assert_eq!(scroll.to_frame_lines(FrameDirection::TopToBottom), expected);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn scroll_frame_lines_computed_in_parallel_match_the_serial_ones() {
+        let scroll = Scroll::new(&font_pixel_frames(
+            "Hola!",
+            PixelColor::RED,
+            PixelColor::BLUE,
+        ));
+        for &direction in [
+            FrameDirection::RightToLeft,
+            FrameDirection::LeftToRight,
+            FrameDirection::TopToBottom,
+            FrameDirection::BottomToTop,
+        ]
+        .iter()
+        {
+            let serial = scroll.to_frame_lines(direction);
+            assert_eq!(scroll.par_to_frame_lines(direction), serial);
+        }
+    }
+
+    #[test]
+    fn scrolls_are_crossfaded_from_the_end_of_one_to_the_start_of_the_other() {
+        let a = Scroll::new(SCROLL_TWO);
+        let b = Scroll::new(&[PixelFrame::BLUE, PixelFrame::GREEN]);
+        let frames = crossfade_scrolls(&a, &b, FrameDirection::RightToLeft, 4);
+        assert_eq!(frames.len(), 5);
+        assert_eq!(frames[0], a.right_to_left().last().unwrap());
+        assert_eq!(frames[0], YLW);
+        assert_eq!(frames[4], b.right_to_left().next().unwrap());
+        assert_eq!(frames[2][0], PixelColor::new(128, 128, 128));
+    }
+
+    #[test]
+    fn scroll_is_optimized_by_dropping_repeated_rendered_frames() {
+        let scroll = Scroll::new(&[BLK, BLK, RED]);
+        let rendered: Vec<PixelFrame> = scroll.right_to_left().collect();
+        assert_eq!(rendered.len(), 17);
+        let optimized = scroll.optimize(FrameDirection::RightToLeft);
+        assert_eq!(optimized.len(), 9);
+        assert_eq!(optimized, &rendered[8..]);
+        let still = Scroll::new(&[BLK, BLK]).optimize(FrameDirection::TopToBottom);
+        assert_eq!(still, vec![BLK]);
+    }
+
+    #[test]
+    fn frame_sequence_steps_by_pixels_and_ends_with_the_final_frame() {
+        let scroll = Scroll::new(&[RED, BLK, YLW]);
+        let all: Vec<PixelFrame> = scroll.left_to_right().collect();
+        let frames: Vec<PixelFrame> = scroll.left_to_right().step_by_pixels(2).collect();
+        let expected: Vec<PixelFrame> = all.iter().cloned().step_by(2).collect();
+        assert_eq!(frames, expected);
+        let frames: Vec<PixelFrame> = scroll.left_to_right().step_by_pixels(3).collect();
+        assert_eq!(frames.len(), 7);
+        assert_eq!(frames[1], all[3]);
+        assert_eq!(frames[6], YLW);
+        assert_eq!(
+            scroll.left_to_right().step_by_pixels(1).collect::<Vec<_>>(),
+            all
+        );
+    }
+
+    #[test]
+    fn scroll_slideshow_holds_each_frame_between_transitions() {
+        let scroll = Scroll::new(&[RED, BLK, YLW]);
+        let hold = Duration::from_secs(2);
+        let slides = scroll.slideshow(hold, 3);
+        assert_eq!(slides.len(), 3 + 2 * 3);
+        let rendered: Vec<PixelFrame> = scroll.right_to_left().collect();
+        let expected = [
+            (RED, true),
+            (rendered[2], false),
+            (rendered[4], false),
+            (rendered[6], false),
+            (BLK, true),
+            (rendered[10], false),
+            (rendered[12], false),
+            (rendered[14], false),
+            (YLW, true),
+        ];
+        for (&(frame, delay), &(expected, held)) in slides.iter().zip(expected.iter()) {
+            assert_eq!(frame, expected);
+            assert_eq!(delay == hold, held);
+        }
+        assert_eq!(scroll.slideshow(hold, 0).len(), 3);
+    }
+
+    #[test]
+    fn scroll_starting_and_ending_with_the_same_frame_is_seamless() {
+        let mut scroll = Scroll::new(&[RED, YLW, RED]);
+        assert!(scroll.is_seamless());
+        scroll.make_seamless();
+        assert_eq!(scroll.len(), 3);
+    }
+
+    #[test]
+    fn scroll_is_made_seamless_by_appending_its_first_frame() {
+        let mut scroll = Scroll::new(&[RED, YLW]);
+        assert!(!scroll.is_seamless());
+        scroll.make_seamless();
+        assert!(scroll.is_seamless());
+        assert_eq!(scroll.frames(), &[RED, YLW, RED]);
+    }
+
     #[test]
     fn scroll_has_frames_method_returns_slice_of_pixel_frames() {
         let scroll = Scroll::new(SCROLL_ONE);
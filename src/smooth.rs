@@ -0,0 +1,92 @@
+//! Temporal smoothing of `PixelFrame`s
+use super::{PixelColor, PixelFrame};
+use std::collections::VecDeque;
+
+/// Smooths a stream of `PixelFrame`s, by averaging each pixel over the last few frames.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameSmoother {
+    depth: usize,
+    frames: VecDeque<PixelFrame>,
+}
+
+impl FrameSmoother {
+    /// Create a new `FrameSmoother` that averages over the last `depth` frames.
+    ///
+    /// # Panics
+    /// If `depth` is `0`.
+    pub fn new(depth: usize) -> Self {
+        assert!(depth > 0);
+        FrameSmoother {
+            depth,
+            frames: VecDeque::with_capacity(depth),
+        }
+    }
+
+    /// Add a frame, dropping the oldest one once `depth` frames are kept, and return the
+    /// per-pixel average of the kept frames.
+    pub fn push(&mut self, frame: PixelFrame) -> PixelFrame {
+        if self.frames.len() == self.depth {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+        self.average()
+    }
+
+    /// Drop every kept frame.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    // The per-pixel average of the kept frames.
+    fn average(&self) -> PixelFrame {
+        let count = self.frames.len() as u32;
+        let mut sums = [(0u32, 0u32, 0u32); 64];
+        for frame in &self.frames {
+            for (idx, sum) in sums.iter_mut().enumerate() {
+                let px = frame[idx];
+                sum.0 += u32::from(px.red);
+                sum.1 += u32::from(px.green);
+                sum.2 += u32::from(px.blue);
+            }
+        }
+        let mut pixels = [PixelColor::BLACK; 64];
+        for (px, &(red, green, blue)) in pixels.iter_mut().zip(sums.iter()) {
+            *px = PixelColor::new(
+                (red / count) as u8,
+                (green / count) as u8,
+                (blue / count) as u8,
+            );
+        }
+        PixelFrame::new(&pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_smoother_averages_alternating_frames_to_mid_gray() {
+        let mut smoother = FrameSmoother::new(2);
+        assert_eq!(smoother.push(PixelFrame::BLACK), PixelFrame::BLACK);
+        let gray = PixelFrame::new(&[PixelColor::new(127, 127, 127); 64]);
+        assert_eq!(smoother.push(PixelFrame::WHITE), gray);
+        assert_eq!(smoother.push(PixelFrame::BLACK), gray);
+    }
+
+    #[test]
+    fn frame_smoother_drops_the_oldest_frames() {
+        let mut smoother = FrameSmoother::new(2);
+        smoother.push(PixelFrame::RED);
+        smoother.push(PixelFrame::BLUE);
+        assert_eq!(smoother.push(PixelFrame::BLUE), PixelFrame::BLUE);
+        smoother.clear();
+        assert_eq!(smoother.push(PixelFrame::RED), PixelFrame::RED);
+    }
+
+    #[test]
+    #[should_panic]
+    fn frame_smoother_with_zero_depth_panics() {
+        let _ = FrameSmoother::new(0);
+    }
+}
@@ -0,0 +1,260 @@
+//! Sprites for the LED Matrix screen
+use super::{PixelColor, PixelFrame};
+
+/// A small bitmap, up to `8x8` pixels, placed at a position on the screen.
+///
+/// Pixels with the `transparent` color are not drawn, letting the background show through.
+/// The position is the `(x, y)` coordinate of the top-left pixel of the sprite, and it may
+/// be partially, or completely, off-screen.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sprite {
+    width: u8,
+    height: u8,
+    pixels: Vec<PixelColor>,
+    position: (i8, i8),
+    transparent: PixelColor,
+}
+
+impl Sprite {
+    /// Create a new `Sprite` at position `(0, 0)`, from the `width * height` pixels of its
+    /// bitmap, in row-major order.
+    ///
+    /// # Panics
+    /// If `width` or `height` are greater than 8, or if the number of pixels is not
+    /// `width * height`.
+    pub fn new(width: u8, height: u8, pixels: &[PixelColor], transparent: PixelColor) -> Self {
+        assert!(width < 9 && height < 9);
+        assert_eq!(pixels.len(), usize::from(width) * usize::from(height));
+        Sprite {
+            width,
+            height,
+            pixels: pixels.to_vec(),
+            position: (0, 0),
+            transparent,
+        }
+    }
+
+    /// The `(width, height)` of the sprite.
+    pub fn size(&self) -> (u8, u8) {
+        (self.width, self.height)
+    }
+
+    /// The `(x, y)` coordinate of the top-left pixel of the sprite.
+    pub fn position(&self) -> (i8, i8) {
+        self.position
+    }
+
+    /// Place the top-left pixel of the sprite at the `(x, y)` coordinate.
+    pub fn set_position(&mut self, position: (i8, i8)) {
+        self.position = position;
+    }
+
+    /// The color that is not drawn.
+    pub fn transparent(&self) -> PixelColor {
+        self.transparent
+    }
+
+    /// Create a new `PixelFrame` with the sprite drawn over the `background`. Pixels that
+    /// fall outside of the screen are clipped.
+    pub fn render_onto(&self, background: &PixelFrame) -> PixelFrame {
+        let mut frame = *background;
+        for (x, y, color) in self.opaque_pixels() {
+            if let (0..=7, 0..=7) = (x, y) {
+                frame[(y * 8 + x) as usize] = color;
+            }
+        }
+        frame
+    }
+
+    /// Returns `true` if the bounding boxes of both sprites overlap, including transparent
+    /// pixels.
+    pub fn overlaps(&self, other: &Sprite) -> bool {
+        let (left, top, right, bottom) = self.bounds();
+        let (other_left, other_top, other_right, other_bottom) = other.bounds();
+        left < other_right && other_left < right && top < other_bottom && other_top < bottom
+    }
+
+    /// Returns `true` if any non-transparent pixel of this sprite shares its position with a
+    /// non-transparent pixel of the `other` sprite.
+    pub fn collides(&self, other: &Sprite) -> bool {
+        if !self.overlaps(other) {
+            return false;
+        }
+        let others: Vec<(i16, i16)> = other.opaque_pixels().map(|(x, y, _)| (x, y)).collect();
+        self.opaque_pixels()
+            .any(|(x, y, _)| others.contains(&(x, y)))
+    }
+
+    // The `(left, top, right, bottom)` screen coordinates of the bounding box, where the
+    // `right`, and `bottom` edges are exclusive.
+    fn bounds(&self) -> (i16, i16, i16, i16) {
+        let (left, top) = (i16::from(self.position.0), i16::from(self.position.1));
+        (
+            left,
+            top,
+            left + i16::from(self.width),
+            top + i16::from(self.height),
+        )
+    }
+
+    // The `(x, y)` screen coordinates, and colors, of every non-transparent pixel.
+    fn opaque_pixels(&self) -> impl Iterator<Item = (i16, i16, PixelColor)> + '_ {
+        let (left, top) = (i16::from(self.position.0), i16::from(self.position.1));
+        let width = usize::from(self.width);
+        self.pixels
+            .iter()
+            .enumerate()
+            .filter(move |&(_, &color)| color != self.transparent)
+            .map(move |(idx, &color)| {
+                let (col, row) = ((idx % width) as i16, (idx / width) as i16);
+                (left + col, top + row, color)
+            })
+    }
+}
+
+/// A background `PixelFrame`, with `Sprite`s layered over it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Scene {
+    /// The frame that the sprites are drawn over.
+    pub background: PixelFrame,
+    /// The sprites, in z-order, with the bottom-most sprite first.
+    pub sprites: Vec<Sprite>,
+}
+
+impl Scene {
+    /// Create a new `Scene` over the `background`, without any sprites.
+    pub fn new(background: PixelFrame) -> Self {
+        Scene {
+            background,
+            sprites: Vec::new(),
+        }
+    }
+
+    /// Add a `Sprite` on top of every other sprite in the scene.
+    pub fn push(&mut self, sprite: Sprite) {
+        self.sprites.push(sprite);
+    }
+
+    /// Create a new `PixelFrame` with every sprite drawn over the background, in z-order.
+    pub fn render(&self) -> PixelFrame {
+        self.sprites
+            .iter()
+            .fold(self.background, |frame, sprite| sprite.render_onto(&frame))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLEAR: PixelColor = PixelColor::BLACK;
+    const RED: PixelColor = PixelColor::RED;
+
+    // A 3x3 plus sign.
+    fn plus() -> Sprite {
+        Sprite::new(
+            3,
+            3,
+            &[
+                CLEAR, RED, CLEAR, //
+                RED, RED, RED, //
+                CLEAR, RED, CLEAR, //
+            ],
+            CLEAR,
+        )
+    }
+
+    #[test]
+    fn sprite_is_rendered_at_the_center() {
+        let mut sprite = plus();
+        sprite.set_position((3, 3));
+        let frame = sprite.render_onto(&PixelFrame::BLUE);
+        let rows = frame.as_rows();
+        assert_eq!(rows[2], [PixelColor::BLUE; 8]);
+        assert_eq!(rows[3][3..6], [PixelColor::BLUE, RED, PixelColor::BLUE]);
+        assert_eq!(
+            rows[4][2..7],
+            [PixelColor::BLUE, RED, RED, RED, PixelColor::BLUE]
+        );
+        assert_eq!(rows[5][3..6], [PixelColor::BLUE, RED, PixelColor::BLUE]);
+        assert_eq!(rows[6], [PixelColor::BLUE; 8]);
+    }
+
+    #[test]
+    fn sprite_is_clipped_at_the_screen_edges() {
+        let mut sprite = plus();
+        sprite.set_position((-1, 6));
+        let frame = sprite.render_onto(&PixelFrame::BLUE);
+        let rows = frame.as_rows();
+        assert_eq!(rows[6][..2], [RED, PixelColor::BLUE]);
+        assert_eq!(rows[7][..3], [RED, RED, PixelColor::BLUE]);
+        let red_pixels = frame
+            .to_rgb565_u16()
+            .iter()
+            .filter(|&&c| c == 0xF800)
+            .count();
+        assert_eq!(red_pixels, 3);
+    }
+
+    #[test]
+    fn sprite_off_screen_leaves_the_background_untouched() {
+        let mut sprite = plus();
+        sprite.set_position((-128, 127));
+        assert_eq!(sprite.render_onto(&PixelFrame::BLUE), PixelFrame::BLUE);
+    }
+
+    #[test]
+    fn sprites_with_overlapping_bounds_but_no_shared_pixels_do_not_collide() {
+        let first = plus();
+        let mut second = plus();
+        second.set_position((2, 2));
+        assert!(first.overlaps(&second));
+        assert!(!first.collides(&second));
+    }
+
+    #[test]
+    fn sprites_with_shared_opaque_pixels_collide() {
+        let first = plus();
+        let mut second = plus();
+        second.set_position((1, 1));
+        assert!(first.overlaps(&second));
+        assert!(first.collides(&second));
+        assert!(second.collides(&first));
+    }
+
+    #[test]
+    fn sprites_apart_neither_overlap_nor_collide() {
+        let first = plus();
+        let mut second = plus();
+        second.set_position((3, 0));
+        assert!(!first.overlaps(&second));
+        assert!(!first.collides(&second));
+    }
+
+    #[test]
+    fn scene_without_sprites_renders_the_background() {
+        assert_eq!(Scene::new(PixelFrame::BLUE).render(), PixelFrame::BLUE);
+    }
+
+    #[test]
+    fn scene_composites_later_sprites_on_top() {
+        let mut scene = Scene::new(PixelFrame::BLUE);
+        scene.push(plus());
+        let mut square = Sprite::new(2, 2, &[PixelColor::GREEN; 4], CLEAR);
+        square.set_position((1, 1));
+        scene.push(square);
+        let rows = scene.render().as_rows();
+        assert_eq!(rows[0][..3], [PixelColor::BLUE, RED, PixelColor::BLUE]);
+        assert_eq!(rows[1][..3], [RED, PixelColor::GREEN, PixelColor::GREEN]);
+        assert_eq!(
+            rows[2][..3],
+            [PixelColor::BLUE, PixelColor::GREEN, PixelColor::GREEN]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn sprite_bitmap_must_have_width_times_height_pixels() {
+        let _ = Sprite::new(3, 3, &[RED; 8], CLEAR);
+    }
+}